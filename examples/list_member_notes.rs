@@ -10,9 +10,8 @@
 /// To run this example you need to create a archive named ``.env`` in the root of the directory with the following info
 /// MAILCHIMP_API_KEY=<API KEY>
 ///
-extern crate md5;
-
 use dotenv::dotenv;
+use mailchimp::types::SubscriberHash;
 use mailchimp::{ListFilter, Lists, MailchimpApi};
 use std::env;
 
@@ -42,7 +41,12 @@ fn main() {
         let mut m_iter = l.get_members(None);
 
         // select one element of the list
-        if let Some(c) = m_iter.next() {
+        if let Some(first) = m_iter.next() {
+            // Get the member again from its email, the library computes the subscriber hash
+            let hash = SubscriberHash::from_email(&first.email_address);
+            println!("\nSubscriber Hash     {}", hash);
+            let c = l.get_member_info(&hash).unwrap();
+
             println!("\nMember:");
             println!("\tEmail Address       {:?}", c.email_address);
             println!("\tUnique Email Id     {:?}", c.unique_email_id);
@@ -19,10 +19,9 @@
 /// paho-mqtt = {version="^0.5", default-features=false}
 ///
 extern crate paho_mqtt as mqtt;
-#[macro_use]
-extern crate serde_derive;
 
 use dotenv::dotenv;
+use serde::{Deserialize, Serialize};
 use std::env;
 use std::process;
 
@@ -152,7 +151,7 @@ impl MailchimpAutomationStats {
         MailchimpAutomationStats {
             measurement_name: "mailchimp_automation".to_string(),
             client_name: account_name.to_string(),
-            status: data.status.clone(),
+            status: data.status.as_ref().map(|s| s.to_string()),
             emails_sent: data.emails_sent.clone(),
             recipients_list_name: r_list_name,
             title: s_title,
@@ -261,8 +260,8 @@ impl MailchimpCampaignStats {
         MailchimpCampaignStats {
             measurement_name: "mailchimp_campaigns".to_string(),
             client_name: account_name.to_string(),
-            campaign_type: data.campaign_type.clone(),
-            status: data.status.clone(),
+            campaign_type: data.campaign_type.as_ref().map(|t| t.to_string()),
+            status: data.status.as_ref().map(|s| s.to_string()),
             emails_sent: data.emails_sent,
             recipients_list_name: settings.0,
             recipients_recipient_count: settings.1,
@@ -52,6 +52,16 @@ impl MailchimpApi {
         }
     }
     ///
+    /// Instancia del API que envía las peticiones a otro dominio, usado en
+    /// las pruebas con un servidor local
+    ///
+    #[cfg(test)]
+    pub(crate) fn with_domain(domain: &str) -> Self {
+        let mut api = MailchimpApi::new("access_token-us6");
+        api.i_api.set_domain(domain);
+        api
+    }
+    ///
     /// Devuelve el dominio
     ///
     pub fn domain(&self) -> String {
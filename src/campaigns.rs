@@ -28,7 +28,10 @@
 use super::api::{MailchimpApi, MailchimpApiUpdate};
 use super::internal::request::MailchimpResult;
 use super::iter::{BuildIter, MalchimpIter, ResourceFilter};
-use super::types::{CampaignType, CampaignsType};
+use super::types::{
+    CampaignBuilder, CampaignKind, CampaignParam, CampaignStatus, CampaignType, CampaignsType,
+    ComposeAndSend, ComposeAndSendResult, FieldSelector, WorkflowStepKind,
+};
 use log::error;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -51,9 +54,9 @@ pub struct CampaignFilter {
     pub offset: Option<u64>,
     /// The campaign type.
     /// regular plaintext absplit rss variate
-    pub campaign_type: Option<String>,
+    pub campaign_type: Option<CampaignKind>,
     /// The status of the campaign.
-    pub status: Option<String>,
+    pub status: Option<CampaignStatus>,
     /// Restrict the response to campaigns sent before the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
     pub before_send_time: Option<String>,
@@ -99,6 +102,32 @@ impl Default for CampaignFilter {
     }
 }
 
+impl CampaignFilter {
+    ///
+    /// Return only the selected fields of each resource in the collection
+    ///
+    pub fn select(mut self, fields: &FieldSelector<CampaignType>) -> Self {
+        self.fields = if fields.is_empty() {
+            None
+        } else {
+            Some(fields.build_for_collection())
+        };
+        self
+    }
+
+    ///
+    /// Exclude the selected fields from each resource in the collection
+    ///
+    pub fn exclude(mut self, fields: &FieldSelector<CampaignType>) -> Self {
+        self.exclude_fields = if fields.is_empty() {
+            None
+        } else {
+            Some(fields.build_exclude_for_collection())
+        };
+        self
+    }
+}
+
 impl ResourceFilter for CampaignFilter {
     fn build_payload(&self) -> HashMap<String, String> {
         let mut payload = HashMap::new();
@@ -127,11 +156,14 @@ impl ResourceFilter for CampaignFilter {
         if self.campaign_type.is_some() {
             payload.insert(
                 "campaign_type".to_string(),
-                self.campaign_type.as_ref().unwrap().clone(),
+                self.campaign_type.as_ref().unwrap().to_string(),
             );
         }
         if self.status.is_some() {
-            payload.insert("status".to_string(), self.status.as_ref().unwrap().clone());
+            payload.insert(
+                "status".to_string(),
+                self.status.as_ref().unwrap().to_string(),
+            );
         }
         if self.before_send_time.is_some() {
             payload.insert(
@@ -231,6 +263,55 @@ impl Campaigns {
         Campaigns { api: Rc::new(api) }
     }
 
+    ///
+    /// Create a new Mailchimp campaign. The required fields of the kind of
+    /// campaign are checked before sending the request, see `CampaignBuilder`.
+    ///
+    /// Argumentos:
+    ///     campaign: Builder with the params of the campaign
+    ///
+    pub fn create(&self, campaign: CampaignBuilder) -> MailchimpResult<CampaignType> {
+        // POST /campaigns
+        let param = campaign.build()?;
+        let mut created = self
+            .api
+            .post::<CampaignType, CampaignParam>("campaigns", param)?;
+        created.set_api(self.api.clone());
+        Ok(created)
+    }
+
+    ///
+    /// Create a campaign, set its content, review the send checklist, send the
+    /// test emails and then send or schedule it. The workflow stops at the first
+    /// step that fails and the result describes every step.
+    ///
+    /// Argumentos:
+    ///     campaign: Builder with the params of the campaign
+    ///     plan: Content, test emails and delivery of the campaign
+    ///
+    pub fn compose_and_send(
+        &self,
+        campaign: CampaignBuilder,
+        plan: &ComposeAndSend,
+    ) -> ComposeAndSendResult {
+        let mut result = ComposeAndSendResult::default();
+        match self.create(campaign) {
+            Ok(created) => {
+                result.done(WorkflowStepKind::Create, "campaign created");
+                plan.run(&created, &mut result);
+            }
+            Err(e) => {
+                result.failed(
+                    WorkflowStepKind::Create,
+                    "the campaign was not created",
+                    Some(e),
+                );
+                result.skip_remaining();
+            }
+        }
+        result
+    }
+
     ///
     /// Get information about a specific list in your Mailchimp account.
     /// Results include list members who have signed up but haven’t confirmed
@@ -48,6 +48,13 @@ where
         }
     }
     ///
+    /// Cambia el dominio del API, usado en las pruebas con un servidor local
+    ///
+    #[cfg(test)]
+    pub fn set_domain(&mut self, domain: &str) {
+        self.domain = domain.to_string();
+    }
+    ///
     /// Devuelve una cadena de texto con el dominio
     ///
     pub fn domain(&self) -> String {
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::{error::Error, fmt};
+use std::{convert::Infallible, error::Error, fmt};
 
 /// ============ Error Response ==============
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -32,6 +32,24 @@ impl Default for MailchimpErrorType {
     }
 }
 
+impl MailchimpErrorType {
+    ///
+    /// Error detected by the library before sending the request, e.g. an
+    /// action that is not valid for the current state of the resource.
+    ///
+    pub(crate) fn invalid_request(detail: &str) -> Self {
+        MailchimpErrorType {
+            error_type:
+                "http://developer.mailchimp.com/documentation/mailchimp/guides/error-glossary/"
+                    .to_string(),
+            title: "Invalid Resource".to_string(),
+            status: 400,
+            detail: detail.to_string(),
+            instance: "".to_string(),
+        }
+    }
+}
+
 impl fmt::Display for MailchimpErrorType {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         write!(
@@ -43,3 +61,9 @@ impl fmt::Display for MailchimpErrorType {
 }
 
 impl Error for MailchimpErrorType {}
+
+impl From<Infallible> for MailchimpErrorType {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
@@ -9,10 +9,21 @@ mod tests {
     use serde::ser::Serialize;
     use serde_json::json;
     use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
 
     use super::api::Api;
-    use super::request::{BasicAuth, HttpReq, MailchimpResult};
+    use super::error_type::MailchimpErrorType;
+    use super::request::{BasicAuth, HttpReq, MailchimpRequest, MailchimpResult};
+    use crate::api::{MailchimpApi, MailchimpApiUpdate};
+    use crate::iter::ResourceFilter;
+    use crate::types::csv::csv_field;
     use crate::types::*;
+    use crate::{Campaigns, LandingPages, Lists};
 
     ///
     ///
@@ -119,6 +130,133 @@ mod tests {
         }
     }
 
+    ///
+    /// Servidor HTTP local que responde a cada petición según su método y su
+    /// ruta y guarda las peticiones recibidas. Permite probar el transporte
+    /// real (`MailchimpRequest`) junto con las acciones de los recursos.
+    ///
+    struct MockServer {
+        domain: String,
+        requests: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl MockServer {
+        ///
+        /// Inicia el servidor con las respuestas (método, ruta, estado, cuerpo).
+        /// La ruta no lleva la versión del API ni los parámetros, las peticiones
+        /// sin respuesta reciben un 404.
+        ///
+        pub fn start(routes: Vec<(&str, &str, u16, &str)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let domain = format!("http://{}/", listener.local_addr().unwrap());
+            let routes: Vec<(String, u16, String)> = routes
+                .into_iter()
+                .map(|(method, path, status, body)| {
+                    (format!("{} {}", method, path), status, body.to_string())
+                })
+                .collect();
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let received = requests.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = match stream {
+                        Ok(stream) => stream,
+                        Err(_) => break,
+                    };
+                    let (request, body) = match read_request(&mut stream) {
+                        Some(request) => request,
+                        None => continue,
+                    };
+                    let (status, resp) = routes
+                        .iter()
+                        .find(|(route, _, _)| *route == request)
+                        .map(|(_, status, resp)| (*status, resp.clone()))
+                        .unwrap_or((
+                            404,
+                            json!({"type": "", "title": "Resource Not Found", "status": 404,
+                                "detail": request.clone(), "instance": ""})
+                            .to_string(),
+                        ));
+                    received.lock().unwrap().push((request, body));
+                    let head = if status == 204 {
+                        "HTTP/1.1 204 No Content\r\n".to_string()
+                    } else {
+                        format!(
+                            "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\n",
+                            status,
+                            resp.len()
+                        )
+                    };
+                    let resp = if status == 204 { "" } else { resp.as_str() };
+                    let _ = write!(stream, "{}Connection: close\r\n\r\n{}", head, resp);
+                }
+            });
+            MockServer { domain, requests }
+        }
+
+        ///
+        /// Instancia del API que envía las peticiones a este servidor
+        ///
+        pub fn api(&self) -> MailchimpApi {
+            MailchimpApi::with_domain(&self.domain)
+        }
+
+        ///
+        /// Peticiones recibidas, "MÉTODO /ruta"
+        ///
+        pub fn requests(&self) -> Vec<String> {
+            let requests = self.requests.lock().unwrap();
+            requests
+                .iter()
+                .map(|(request, _)| request.clone())
+                .collect()
+        }
+
+        ///
+        /// Cuerpo JSON de la última petición recibida con ese método y ruta
+        ///
+        pub fn body_of(&self, request: &str) -> serde_json::Value {
+            let requests = self.requests.lock().unwrap();
+            let body = requests
+                .iter()
+                .rev()
+                .find(|(r, _)| r == request)
+                .map(|(_, body)| body.clone())
+                .unwrap_or_default();
+            serde_json::from_str(&body).unwrap_or(serde_json::Value::Null)
+        }
+    }
+
+    ///
+    /// Lee una petición HTTP y devuelve "MÉTODO /ruta" y el cuerpo
+    ///
+    fn read_request(stream: &mut TcpStream) -> Option<(String, String)> {
+        let mut reader = BufReader::new(stream.try_clone().ok()?);
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let mut length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).ok()?;
+            if header.trim().is_empty() {
+                break;
+            }
+            let header = header.to_lowercase();
+            if let Some(value) = header.strip_prefix("content-length:") {
+                length = value.trim().parse().unwrap_or(0);
+            }
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).ok()?;
+        let mut parts = line.split_whitespace();
+        let method = parts.next()?;
+        let path = parts.next()?.split('?').next()?;
+        Some((
+            format!("{} {}", method, path.trim_start_matches("/3.0")),
+            String::from_utf8_lossy(&body).to_string(),
+        ))
+    }
+
     ///
     /// Configura la instancia de API para los diferentes Test que no requieren de
     /// una respuesta
@@ -367,5 +505,1832 @@ mod tests {
             resp.reports.len(), 4
         );
     }
+    #[test]
+    fn test_member_fields_projection() {
+        let fields = ListMember::fields()
+            .email_address()
+            .merge_fields()
+            .stats_avg_open_rate();
+        assert_eq!(
+            fields.build_for_collection(),
+            "members.email_address,members.merge_fields,members.stats.avg_open_rate,total_items",
+            "Los estados de la petición no coinciden: Valor de la respuesta {:?}",
+            fields.build_for_collection()
+        );
+        let filter = ListMembersFilter::default().select(&fields);
+        assert_eq!(filter.fields, Some(fields.build_for_collection()));
+
+        let mock_transport = MockRequest::new(
+            "{\"members\":[{\"email_address\":\"urist.mcvankab@freddiesjokes.com\",\"merge_fields\":{\"FNAME\":\"Urist\"}}],\"total_items\":1}",
+            "",
+        );
+        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let resp = api
+            .get_edge::<CollectionPartialListMembers>(
+                "/lists/{list_id}/members",
+                filter.build_payload(),
+            )
+            .unwrap();
+        let member = &resp.members[0];
+        assert_eq!(
+            member.email_address.as_ref().unwrap(),
+            "urist.mcvankab@freddiesjokes.com",
+            "Los estados de la petición no coinciden: Valor de la respuesta {:?}",
+            member.email_address
+        );
+        assert!(
+            member.status.is_none(),
+            "Los campos no solicitados deben ser None"
+        );
+    }
+    #[test]
+    fn test_status_enums_with_unknown_values() {
+        let member: ListMember =
+            serde_json::from_str("{\"id\":\"1\",\"status\":\"subscribed\"}").unwrap();
+        assert_eq!(
+            member.status, MemberStatus::Subscribed,
+            "Los estados de la petición no coinciden: Valor de la respuesta {:?} Valor esperado: {:?}",
+            member.status, MemberStatus::Subscribed
+        );
+
+        let campaign: CampaignType =
+            serde_json::from_str("{\"id\":\"1\",\"type\":\"regular\",\"status\":\"new_status\"}")
+                .unwrap();
+        assert_eq!(campaign.campaign_type, Some(CampaignKind::Regular));
+        assert_eq!(
+            campaign.status,
+            Some(CampaignStatus::Unknown("new_status".to_string()))
+        );
+        assert_eq!(
+            serde_json::to_value(&campaign.status).unwrap(),
+            json!("new_status")
+        );
+
+        let params: ListMemberParams =
+            serde_json::from_value(json!({"status_if_new": "pending"})).unwrap();
+        assert_eq!(params.status_if_new, Some(MemberStatus::Pending));
+    }
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_member_timestamps_with_chrono() {
+        use chrono::{TimeZone, Utc};
+
+        let member: ListMember = serde_json::from_str(
+            "{\"id\":\"1\",\"timestamp_signup\":\"\",\"timestamp_opt\":\"2015-09-16T19:24:29+00:00\",\"last_changed\":\"2015-09-16 19:24:29\"}",
+        )
+        .unwrap();
+        assert_eq!(
+            member.timestamp_signup, "",
+            "El campo sigue siendo un String"
+        );
+        assert_eq!(member.timestamp_signup_utc(), None);
+        assert_eq!(
+            member.timestamp_opt_utc(),
+            Some(Utc.with_ymd_and_hms(2015, 9, 16, 19, 24, 29).unwrap()),
+            "Los estados de la petición no coinciden: Valor de la respuesta {:?}",
+            member.timestamp_opt
+        );
+        assert_eq!(member.last_changed_utc(), member.timestamp_opt_utc());
+
+        let growth: ListGrowthHistoryType =
+            serde_json::from_str("{\"list_id\":\"1\",\"month\":\"2015-09\"}").unwrap();
+        assert_eq!(growth.month, "2015-09");
+        assert_eq!(
+            growth.month_utc(),
+            Some(Utc.with_ymd_and_hms(2015, 9, 1, 0, 0, 0).unwrap())
+        );
+    }
+    #[test]
+    fn test_merge_values_and_validation() {
+        let member: ListMember = serde_json::from_value(json!({
+            "id": "1",
+            "merge_fields": {
+                "FNAME": "Urist",
+                "AGE": 42,
+                "ADDRESS": {"addr1": "675 Ponce De Leon Ave NE", "city": "Atlanta", "state": "GA", "zip": "30308", "country": "US"}
+            }
+        }))
+        .unwrap();
+        assert_eq!(member.merge_fields["AGE"], MergeValue::from(42));
+        assert_eq!(
+            serde_json::to_string(&member.merge_fields["AGE"]).unwrap(),
+            "42",
+            "Un entero se serializa sin decimales"
+        );
+        assert_eq!(
+            serde_json::to_value(&member.merge_fields).unwrap()["AGE"],
+            json!(42)
+        );
+        assert_eq!(member.merge_fields["AGE"].as_f64(), Some(42.0));
+        assert_eq!(
+            serde_json::to_value(MergeValue::from(4.5)).unwrap(),
+            json!(4.5)
+        );
+        assert_eq!(
+            member.merge_fields["ADDRESS"].as_address().unwrap().city,
+            "Atlanta"
+        );
+
+        let fields: Vec<ListMergeField> = serde_json::from_value(json!([
+            {"tag": "FNAME", "type": "text", "required": true},
+            {"tag": "AGE", "type": "number"},
+            {"tag": "COLOR", "type": "dropdown", "options": {"choices": ["Red", "Blue"]}},
+            {"tag": "BDAY", "type": "birthday", "options": {"date_format": "MM/DD"}}
+        ]))
+        .unwrap();
+        let mut values = HashMap::new();
+        values.insert("AGE".to_string(), MergeValue::from("forty"));
+        values.insert("COLOR".to_string(), MergeValue::from("Green"));
+        values.insert("BDAY".to_string(), MergeValue::from("12/24"));
+        values.insert("LNAME".to_string(), MergeValue::from("McVankab"));
+
+        let errors = validate_merge_values(&fields, &values, true);
+        let tags: Vec<&str> = errors.iter().map(|e| e.tag.as_str()).collect();
+        assert_eq!(
+            tags,
+            vec!["AGE", "COLOR", "FNAME", "LNAME"],
+            "Los errores no coinciden: {:?}",
+            errors
+        );
+        assert_eq!(
+            MergeValue::from("42").typed(&fields[1]),
+            MergeValue::from(42)
+        );
+    }
+    #[test]
+    fn test_member_interests_as_booleans() {
+        let member: ListMember = serde_json::from_value(json!({
+            "id": "1",
+            "interests": {"9143cf3bd1": true, "3a2a927344": false}
+        }))
+        .unwrap();
+        assert!(member.is_interested("9143cf3bd1"));
+        assert!(!member.is_interested("3a2a927344"));
+        assert!(!member.is_interested("unknown"));
+
+        let mut params = ListMemberParams::default();
+        params.set_interest("9143cf3bd1", true);
+        params.set_interest("3a2a927344", false);
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            json!({"interests": {"9143cf3bd1": true, "3a2a927344": false}})
+        );
+    }
+    #[test]
+    fn test_member_interests_as_string_booleans() {
+        let member: ListMember = serde_json::from_value(json!({
+            "id": "1",
+            "interests": {"9143cf3bd1": "true", "3a2a927344": "false", "f9c8f5f0ff": " TRUE "}
+        }))
+        .unwrap();
+        assert!(member.is_interested("9143cf3bd1"), "\"true\" es verdadero");
+        assert!(!member.is_interested("3a2a927344"), "\"false\" es falso");
+        assert!(
+            member.is_interested("f9c8f5f0ff"),
+            "Sin distinguir mayúsculas"
+        );
+
+        let partial: PartialListMember = serde_json::from_value(json!({
+            "interests": {"9143cf3bd1": "false", "3a2a927344": true}
+        }))
+        .unwrap();
+        let interests = partial.interests.unwrap();
+        assert_eq!(interests.get("9143cf3bd1"), Some(&false));
+        assert_eq!(interests.get("3a2a927344"), Some(&true));
+    }
+    #[test]
+    fn test_segment_builder_golden_json() {
+        let options: SegmentOptionsType = Segment::all()
+            .merge("FNAME")
+            .is("Ana")
+            .and(Date::timestamp_opt().within_days(30))
+            .and(Interests::category("bd3a0fbd95").contains_any(&["9143cf3bd1"]))
+            .and(StaticSegment::member_of(48501))
+            .and(Vip::notmember())
+            .into();
+        let golden = json!({
+            "match": "all",
+            "conditions": [
+                {"condition_type": "TextMerge", "field": "FNAME", "op": "is", "value": "Ana"},
+                {"condition_type": "Date", "field": "timestamp_opt", "op": "within", "value": 30},
+                {
+                    "condition_type": "Interests",
+                    "field": "interests-bd3a0fbd95",
+                    "op": "interestcontains",
+                    "value": ["9143cf3bd1"]
+                },
+                {
+                    "condition_type": "StaticSegment",
+                    "field": "static_segment",
+                    "op": "static_is",
+                    "value": 48501
+                },
+                {"condition_type": "VIP", "field": "gmonkey", "op": "notmember"}
+            ]
+        });
+        let value = serde_json::to_value(&options).unwrap();
+        assert_eq!(value, golden, "El JSON no coincide: {}", value);
+
+        let parsed: SegmentOptionsType = serde_json::from_value(golden.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), golden);
+        assert_eq!(parsed.conditions[1].op(), SegmentConditionOp::Within);
+        assert_eq!(parsed.conditions[2].field(), "interests-bd3a0fbd95");
+    }
+    #[test]
+    fn test_segment_condition_without_type() {
+        let options: SegmentOptionsType = serde_json::from_value(json!({
+            "match": "any",
+            "conditions": [
+                {"field": "static_segment", "op": "static_is", "value": 48501},
+                {"field": "date", "op": "date_within", "value": "7"},
+                {"condition_type": "NewSubsribers", "field": "date", "op": "date_within", "value": "7"}
+            ]
+        }))
+        .unwrap();
+        let types: Vec<&str> = options
+            .conditions
+            .iter()
+            .map(|c| c.condition_type())
+            .collect();
+        assert_eq!(
+            types,
+            vec!["StaticSegment", "NewSubscribers", "NewSubscribers"]
+        );
+        assert_eq!(
+            serde_json::to_value(&options.conditions[1]).unwrap(),
+            json!({"condition_type": "NewSubscribers", "field": "date", "op": "date_within", "value": "7"})
+        );
+        assert!(serde_json::from_value::<SegmentConditionsType>(json!({
+            "condition_type": "Nope", "field": "x", "op": "is", "value": 1
+        }))
+        .is_err());
+
+        let unknown_op: SegmentConditionsType = serde_json::from_value(json!({
+            "condition_type": "TextMerge", "field": "FNAME", "op": "regex", "value": "^A"
+        }))
+        .unwrap();
+        assert_eq!(
+            unknown_op.op(),
+            SegmentConditionOp::Unknown("regex".to_string()),
+            "Operador desconocido"
+        );
+        assert_eq!(
+            serde_json::to_value(&unknown_op).unwrap()["op"],
+            json!("regex")
+        );
+
+        assert!(MemberRating::is(0).is_err(), "Valoración fuera de rango");
+        assert!(MemberRating::less(9).is_err(), "Valoración fuera de rango");
+        assert_eq!(
+            serde_json::to_value(MemberRating::is(5).unwrap()).unwrap(),
+            json!({"condition_type": "MemberRating", "field": "rating", "op": "is", "value": 5})
+        );
+    }
+    #[test]
+    fn test_segment_local_preview() {
+        let members: Vec<ListMember> = serde_json::from_value(json!([
+            {
+                "id": "1", "email_address": "ana@example.com", "status": "subscribed",
+                "merge_fields": {"FNAME": "Ana"}, "interests": {"9143cf3bd1": true},
+                "member_rating": 4, "language": "es", "vip": true,
+                "timestamp_opt": "2020-06-20T10:00:00+00:00",
+                "last_changed": "2020-06-20T10:00:00+00:00",
+                "location": {"country_code": "ES"},
+                "tags": [{"id": 48501, "name": "Customers"}]
+            },
+            {
+                "id": "2", "email_address": "ana@old.com", "status": "subscribed",
+                "merge_fields": {"FNAME": "ana"}, "interests": {"9143cf3bd1": false},
+                "member_rating": 2, "language": "en", "vip": false,
+                "timestamp_opt": "2019-01-01T10:00:00+00:00",
+                "last_changed": "2019-01-01T10:00:00+00:00"
+            },
+            {
+                "id": "3", "email_address": "ana@gone.com", "status": "unsubscribed",
+                "merge_fields": {"FNAME": "Ana"},
+                "timestamp_opt": "2020-06-25T10:00:00+00:00",
+                "last_changed": "2020-06-25T10:00:00+00:00"
+            }
+        ]))
+        .unwrap();
+        let evaluator = SegmentEvaluator::at("2020-07-01").unwrap();
+
+        let recent = Segment::all()
+            .merge("FNAME")
+            .is("Ana")
+            .and(Date::timestamp_opt().within_days(30))
+            .build();
+        let preview = evaluator.preview(&recent, &members);
+        assert_eq!(preview.emails(), vec!["ana@example.com"]);
+        assert!(preview.is_exact());
+
+        let everyone = evaluator
+            .clone()
+            .include_all_statuses()
+            .preview(&recent, &members);
+        assert_eq!(everyone.emails(), vec!["ana@example.com", "ana@gone.com"]);
+
+        let targeting = Segment::any()
+            .and(Interests::category("bd3a0fbd95").contains_any(&["9143cf3bd1"]))
+            .and(MemberRating::greater(3).unwrap())
+            .and(Language::is("es"))
+            .and(Vip::member())
+            .and(Location::country("ES"))
+            .and(StaticSegment::member_of(48501))
+            .and(EmailAddress::ends("@old.com"))
+            .build();
+        let preview = evaluator.preview(&targeting, &members);
+        assert_eq!(preview.emails(), vec!["ana@example.com", "ana@old.com"]);
+
+        let mut with_aim = Segment::all().and(Vip::member()).build();
+        with_aim.conditions.push(
+            serde_json::from_value(json!({
+                "condition_type": "Aim", "field": "aim", "op": "open", "value": "any"
+            }))
+            .unwrap(),
+        );
+        let preview = evaluator.preview(&with_aim, &members);
+        assert!(
+            preview.emails().is_empty(),
+            "No se puede afirmar que coincide"
+        );
+        assert_eq!(preview.undetermined_emails(), vec!["ana@example.com"]);
+        assert_eq!(preview.unsupported.len(), 1);
+        assert_eq!(preview.unsupported[0].index, 1);
+        assert_eq!(preview.unsupported[0].condition_type, "Aim");
+
+        let aim = |options: &mut SegmentOptionsType| {
+            options.conditions.push(
+                serde_json::from_value(json!({
+                    "condition_type": "Aim", "field": "aim", "op": "open", "value": "any"
+                }))
+                .unwrap(),
+            )
+        };
+        for mut only_aim in [Segment::all().build(), Segment::any().build()] {
+            aim(&mut only_aim);
+            let preview = evaluator.preview(&only_aim, &members);
+            assert!(
+                preview.emails().is_empty(),
+                "Ninguna condición evaluable: {}",
+                only_aim.match_filter
+            );
+            assert_eq!(
+                preview.undetermined_emails(),
+                vec!["ana@example.com", "ana@old.com"]
+            );
+        }
+
+        let mut any_with_aim = Segment::any().and(Vip::member()).build();
+        aim(&mut any_with_aim);
+        let preview = evaluator.preview(&any_with_aim, &members);
+        assert_eq!(preview.emails(), vec!["ana@example.com"]);
+        assert_eq!(preview.undetermined_emails(), vec!["ana@old.com"]);
+    }
+    #[test]
+    fn test_tag_search_and_bulk_result() {
+        let tags: CollectionListTags = serde_json::from_value(json!({
+            "tags": [{"id": 48501, "name": "Customers"}, {"id": 48502, "name": "Customers VIP"}],
+            "total_items": 2
+        }))
+        .unwrap();
+        assert_eq!(tags.tags[1].name, "Customers VIP");
+
+        let chunk = |added: u64, errors: serde_json::Value| -> BatchListMembersStaticSegment {
+            serde_json::from_value(json!({
+                "members_added": [],
+                "members_removed": [],
+                "errors": errors,
+                "total_added": added,
+                "total_removed": 0,
+                "error_count": 0
+            }))
+            .unwrap()
+        };
+        let mut result = BulkStaticSegmentResult::default();
+        result.add_response(chunk(2, json!([])));
+        result.add_response(chunk(
+            1,
+            json!([{"email_address": "bad@example.com", "error": "not subscribed"}]),
+        ));
+        result.add_failure(
+            vec!["x@example.com".to_string()],
+            MailchimpErrorType::invalid_request("timeout"),
+        );
+        assert_eq!(result.requests, 3);
+        assert_eq!(result.total_added, 3);
+        assert!(!result.is_ok());
+        assert_eq!(
+            result.failed_emails(),
+            vec!["bad@example.com".to_string(), "x@example.com".to_string()]
+        );
+
+        let filter = ListSegmentFilter {
+            s_type: Some("static".to_string()),
+            ..ListSegmentFilter::default()
+        };
+        assert_eq!(
+            filter.build_payload().get("type"),
+            Some(&"static".to_string())
+        );
+    }
+    #[test]
+    fn test_subscriber_hash_from_email_or_hash() {
+        let expected = "62eeb292278cc15f5817cb78f7790b08";
+        let from_email = SubscriberHash::try_from(" Urist.McVankab@FreddiesJokes.com ").unwrap();
+        assert_eq!(from_email.as_str(), expected, "Hash del email normalizado");
+        assert_eq!(
+            SubscriberHash::try_from("urist.mcvankab@freddiesjokes.com").unwrap(),
+            from_email,
+            "Mayúsculas y espacios no cambian el hash"
+        );
+
+        let from_hash = SubscriberHash::try_from("62EEB292278CC15F5817CB78F7790B08").unwrap();
+        assert_eq!(
+            from_hash.as_str(),
+            expected,
+            "Un hash no se vuelve a calcular"
+        );
+        assert_eq!(
+            SubscriberHash::try_from(expected.to_string()).unwrap(),
+            from_hash
+        );
+
+        assert_eq!(SubscriberHash::from_hash("not-a-hash"), None);
+        for invalid in &["abc", "foo/../bar", "62eeb292278cc15f5817cb78f7790b0g", ""] {
+            let err = SubscriberHash::try_from(*invalid).unwrap_err();
+            assert_eq!(err.status, 400, "`{}` no es un hash válido", invalid);
+        }
+        assert_eq!(
+            serde_json::to_value(&from_email).unwrap(),
+            json!(expected),
+            "Se serializa como texto"
+        );
+    }
+    #[test]
+    fn test_member_events() {
+        let events: CollectionListMemberEvents = serde_json::from_value(json!({
+            "events": [
+                {"name": "purchased", "properties": {"sku": "A-1"}, "occurred_at": "2020-06-20T10:00:00+00:00"},
+                {"name": "visited", "properties": {}, "occurred_at": ""}
+            ],
+            "total_items": 2
+        }))
+        .unwrap();
+        assert_eq!(events.events.len(), 2);
+        assert_eq!(events.events[0].properties["sku"], "A-1");
+        assert_eq!(events.events[1].name, "visited");
+
+        let mut param = ListMemberEventParam {
+            name: "purchased".to_string(),
+            is_syncing: true,
+            ..ListMemberEventParam::default()
+        };
+        param
+            .properties
+            .insert("sku".to_string(), "A-1".to_string());
+        assert!(param.validate().is_ok());
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            json!({"name": "purchased", "properties": {"sku": "A-1"}, "is_syncing": true})
+        );
+        param.name = "bought it!".to_string();
+        assert!(param.validate().is_err());
+
+        let endpoint = "/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08/events";
+        let server = MockServer::start(vec![("POST", endpoint, 204, "")]);
+        let mut member: ListMember = serde_json::from_value(json!({
+            "id": "62eeb292278cc15f5817cb78f7790b08",
+            "email_address": "urist.mcvankab@freddiesjokes.com",
+            "list_id": "57afe96172"
+        }))
+        .unwrap();
+        member.set_api(Rc::new(server.api()));
+        let error = member.create_event("purchased", HashMap::new(), None, false);
+        assert!(error.is_none(), "{:?}", error);
+        assert_eq!(server.requests(), vec![format!("POST {}", endpoint)]);
+    }
+    #[test]
+    fn test_request_http_verbs() {
+        let verbs = ["GET", "POST", "PATCH", "PUT", "DELETE"];
+        let server = MockServer::start(verbs.iter().map(|v| (*v, "/verbs", 200, "{}")).collect());
+        let url = Url::parse(&server.domain)
+            .unwrap()
+            .join("3.0/verbs")
+            .unwrap();
+        let req = MailchimpRequest::new();
+        let auth = Some(BasicAuth {
+            username: "".to_string(),
+            api_token: "access_token".to_string(),
+        });
+        for basic_auth in &[auth, None] {
+            let payload = HashMap::<String, String>::new();
+            assert!(req.get(url.clone(), HeaderMap::new(), basic_auth).is_ok());
+            assert!(req
+                .post(url.clone(), HeaderMap::new(), &payload, basic_auth)
+                .is_ok());
+            assert!(req
+                .patch(url.clone(), HeaderMap::new(), &payload, basic_auth)
+                .is_ok());
+            assert!(req
+                .put(url.clone(), HeaderMap::new(), &payload, basic_auth)
+                .is_ok());
+            assert!(req
+                .delete(url.clone(), HeaderMap::new(), basic_auth)
+                .is_ok());
+        }
+        let expected: Vec<String> = verbs.iter().map(|v| format!("{} /verbs", v)).collect();
+        assert_eq!(server.requests()[..5], expected[..], "Con autenticación");
+        assert_eq!(server.requests()[5..], expected[..], "Sin autenticación");
+    }
+    #[test]
+    fn test_member_upsert_is_sent_as_put() {
+        let endpoint = "/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08";
+        let info = json!({
+            "id": "62eeb292278cc15f5817cb78f7790b08",
+            "email_address": "urist.mcvankab@freddiesjokes.com",
+            "status": "subscribed",
+            "list_id": "57afe96172"
+        })
+        .to_string();
+        let server = MockServer::start(vec![("PUT", endpoint, 200, &info)]);
+        let mut member: ListMember = serde_json::from_str(&info).unwrap();
+        member.set_api(Rc::new(server.api()));
+        let upserted = member.upsert(ListMemberParams::default()).unwrap();
+        assert_eq!(upserted.status, MemberStatus::Subscribed);
+        assert_eq!(server.requests(), vec![format!("PUT {}", endpoint)]);
+    }
+    #[test]
+    fn test_member_lifecycle_guards() {
+        let member = |status: &str| -> ListMember {
+            serde_json::from_value(json!({
+                "id": "62eeb292278cc15f5817cb78f7790b08",
+                "email_address": "urist.mcvankab@freddiesjokes.com",
+                "list_id": "57afe96172",
+                "status": status
+            }))
+            .unwrap()
+        };
+        assert!(member("subscribed").change_email("not-an-email").is_err());
+        for status in &["archived", "cleaned", "transactional", ""] {
+            assert!(
+                member(status).upsert(ListMemberParams::default()).is_err(),
+                "status_if_new no puede ser {:?}",
+                status
+            );
+        }
+        let archived = ListMemberParams {
+            status_if_new: Some(MemberStatus::Archived),
+            ..ListMemberParams::default()
+        };
+        assert!(member("subscribed").upsert(archived).is_err());
+
+        let endpoint = "/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08";
+        let updated = json!({"id": "62eeb292278cc15f5817cb78f7790b08", "status": "pending"});
+        let server = MockServer::start(vec![("PATCH", endpoint, 200, &updated.to_string())]);
+        let mut stale = member("pending");
+        stale.set_api(Rc::new(server.api()));
+        assert!(
+            stale.resubscribe().is_ok(),
+            "El estado local puede estar desactualizado, decide el API"
+        );
+        assert_eq!(
+            server.body_of(&format!("PATCH {}", endpoint)),
+            json!({"status": "pending"})
+        );
+    }
+    #[test]
+    fn test_member_activity_feed() {
+        let feed: CollectionMemberActivityFeed = serde_json::from_value(json!({
+            "activity": [
+                {
+                    "activity_type": "click",
+                    "created_at_timestamp": "2020-06-20T10:00:00+00:00",
+                    "campaign_id": "42694e9e57",
+                    "campaign_title": "Freddie's Jokes Vol. 1",
+                    "link_clicked": "https://freddiesjokes.com"
+                },
+                {
+                    "activity_type": "event",
+                    "created_at_timestamp": "2020-06-21T10:00:00+00:00",
+                    "event_name": "purchased",
+                    "event_properties": {"sku": "A-1"}
+                },
+                {
+                    "activity_type": "note",
+                    "created_at_timestamp": "2020-06-22T10:00:00+00:00",
+                    "note_id": 10505,
+                    "note_text": "Favorite joke",
+                    "created_by": "Freddie"
+                },
+                {
+                    "activity_type": "postcard",
+                    "created_at_timestamp": "2020-06-23T10:00:00+00:00",
+                    "postcard_id": "pc-1"
+                }
+            ],
+            "list_id": "57afe96172",
+            "email_id": "62eeb292278cc15f5817cb78f7790b08",
+            "total_items": 4
+        }))
+        .unwrap();
+        let types: Vec<&str> = feed.activity.iter().map(|a| a.activity_type()).collect();
+        assert_eq!(types, vec!["click", "event", "note", "postcard"]);
+        match &feed.activity[0] {
+            MemberActivityFeedItem::Click(c) => {
+                assert_eq!(c.link_clicked, "https://freddiesjokes.com")
+            }
+            other => panic!("Se esperaba un click: {:?}", other),
+        }
+        assert_eq!(
+            feed.activity[3].created_at().map(|t| t.as_str()),
+            Some("2020-06-23T10:00:00+00:00")
+        );
+        match &feed.activity[3] {
+            MemberActivityFeedItem::Other(v) => {
+                assert_eq!(v["postcard_id"], json!("pc-1"), "Se conservan los datos")
+            }
+            other => panic!("Se esperaba una actividad desconocida: {:?}", other),
+        }
+        let round_trip = serde_json::to_value(&feed.activity).unwrap();
+        assert_eq!(round_trip[0]["activity_type"], json!("click"));
+        assert_eq!(
+            round_trip[0]["link_clicked"],
+            json!("https://freddiesjokes.com")
+        );
+        assert_eq!(round_trip[3]["activity_type"], json!("postcard"));
+        assert_eq!(round_trip[3]["postcard_id"], json!("pc-1"));
+
+        let filter = ActivityFeedFilter::default().only(&["open", "click"]);
+        assert_eq!(
+            filter.build_payload().get("activity_filters"),
+            Some(&"open,click".to_string())
+        );
+    }
+
+    #[test]
+    fn test_consent_audit_export() {
+        let member: PartialListMember = serde_json::from_value(json!({
+            "email_address": "urist.mcvankab@freddiesjokes.com",
+            "marketing_permissions": [
+                {"marketing_permission_id": "abc", "text": "Email", "enabled": true},
+                {"marketing_permission_id": "def", "text": "Direct Mail, Post", "enabled": false}
+            ]
+        }))
+        .unwrap();
+        let permissions = member.marketing_permissions.clone().unwrap();
+        assert_eq!(
+            find_marketing_permission(&permissions, " email ")
+                .map(|p| p.marketing_permission_id.as_str()),
+            Some("abc")
+        );
+        assert!(find_marketing_permission(&permissions, "def").is_some());
+        assert!(find_marketing_permission(&permissions, "SMS").is_none());
+
+        let records = ConsentRecord::from_member(&member);
+        assert_eq!(records.len(), 2, "Debe haber un registro por permiso");
+        let mut out = Vec::new();
+        write_consent_csv(&records, &mut out).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "email_address,marketing_permission_id,text,enabled,member_last_changed"
+        );
+        assert_eq!(lines[1], "urist.mcvankab@freddiesjokes.com,abc,Email,true,");
+        assert_eq!(
+            lines[2],
+            "urist.mcvankab@freddiesjokes.com,def,\"Direct Mail, Post\",false,"
+        );
+        assert_eq!(
+            csv_field("Email\rOnly"),
+            "\"Email\rOnly\"",
+            "Un retorno de carro debe ir entre comillas"
+        );
+        assert_eq!(csv_field("Email"), "Email");
+        assert_eq!(
+            csv_field("=HYPERLINK(\"http://evil.example\",\"Click\")"),
+            "\"'=HYPERLINK(\"\"http://evil.example\"\",\"\"Click\"\")\"",
+            "Una fórmula no se debe ejecutar al abrir el fichero"
+        );
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("\tEmail"), "'\tEmail");
+        assert_eq!(csv_field("-0.05"), "-0.05", "Los números no cambian");
+    }
+
+    #[test]
+    fn test_data_subject_export_and_report() {
+        let member: ListMember = serde_json::from_value(json!({
+            "id": "62eeb292278cc15f5817cb78f7790b08",
+            "email_address": "urist.mcvankab@freddiesjokes.com",
+            "status": "subscribed",
+            "list_id": "57afe96172",
+            "merge_fields": {"FNAME": "Urist", "LNAME": "McVankab", "PHONE": "555", "CITY": "Atlanta",
+                "ZIP": "30308", "PLAN": "Gold", "POINTS": 10, "NICK": "Uri"}
+        }))
+        .unwrap();
+        let mut export = DataSubjectExport::new(" urist.mcvankab@freddiesjokes.com ");
+        assert_eq!(
+            export.subscriber_hash.as_str(),
+            "62eeb292278cc15f5817cb78f7790b08"
+        );
+        export.memberships.push(MemberDataExport {
+            list_id: "57afe96172".to_string(),
+            list_name: "Freddie's Jokes".to_string(),
+            member,
+            notes: Vec::new(),
+            tags: vec!["vip".to_string()],
+            activity: Vec::new(),
+            events: Vec::new(),
+        });
+        export
+            .failed
+            .push(("b2c3d4e5f6".to_string(), MailchimpErrorType::default()));
+        let bundle: serde_json::Value = serde_json::from_str(&export.to_json().unwrap()).unwrap();
+        assert_eq!(
+            bundle["memberships"][0]["member"]["email_address"],
+            json!("urist.mcvankab@freddiesjokes.com")
+        );
+        assert_eq!(bundle["memberships"][0]["tags"], json!(["vip"]));
+        let digest = export.digest().unwrap();
+        assert_eq!(digest.len(), 32, "El digest debe ser un MD5");
+        let reloaded: DataSubjectExport = serde_json::from_str(&export.to_json().unwrap()).unwrap();
+        assert_eq!(
+            reloaded.digest().unwrap(),
+            digest,
+            "El digest no depende del orden de las claves"
+        );
+        assert_eq!(export.list_ids(), vec!["57afe96172".to_string()]);
+
+        let mut fields = HashMap::new();
+        for (key, value) in &[
+            ("ZIP", "30308"),
+            ("CITY", "Atlanta"),
+            ("PLAN", "Gold"),
+            ("FNAME", "Urist"),
+        ] {
+            fields.insert(key.to_string(), value.to_string());
+        }
+        let value = json!({"merge_fields": fields, "b": [{"y": 1, "x": 2}], "a": null});
+        assert_eq!(
+            canonical_json(&value).unwrap(),
+            "{\"a\":null,\"b\":[{\"x\":2,\"y\":1}],\"merge_fields\":{\"CITY\":\"Atlanta\",\"FNAME\":\"Urist\",\"PLAN\":\"Gold\",\"ZIP\":\"30308\"}}",
+            "Las claves se ordenan en todos los niveles"
+        );
+
+        let mut report = ErasureReport::from_export(&export).unwrap();
+        assert_eq!(report.export_digest, digest);
+        report.add_result("57afe96172", None);
+        assert_eq!(report.erased, vec!["57afe96172".to_string()]);
+        assert!(
+            !report.is_complete(),
+            "La lista no leída debe quedar como fallo"
+        );
+        let summary = report.summary();
+        assert!(summary.contains("removed from 57afe96172"));
+        assert!(summary.contains("FAILED b2c3d4e5f6"));
+        assert!(summary.ends_with("Status: incomplete, 1 failures"));
+    }
+    #[test]
+    fn test_erase_member_with_no_content_response() {
+        let member = "/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08";
+        let lists =
+            json!({"lists": [{"id": "57afe96172", "name": "Freddie's Jokes"}], "total_items": 1})
+                .to_string();
+        let info = json!({
+            "id": "62eeb292278cc15f5817cb78f7790b08",
+            "email_address": "urist.mcvankab@freddiesjokes.com",
+            "status": "subscribed",
+            "list_id": "57afe96172"
+        })
+        .to_string();
+        let notes = format!("{}/notes", member);
+        let tags = format!("{}/tags", member);
+        let feed = format!("{}/activity-feed", member);
+        let events = format!("{}/events", member);
+        let delete = format!("{}/actions/delete-permanent", member);
+        let server = MockServer::start(vec![
+            ("GET", "/lists", 200, &lists),
+            ("GET", member, 200, &info),
+            ("GET", &notes, 200, "{\"notes\": [], \"total_items\": 0}"),
+            ("GET", &tags, 200, "{\"tags\": [], \"total_items\": 0}"),
+            ("GET", &feed, 200, "{\"activity\": [], \"total_items\": 0}"),
+            ("GET", &events, 200, "{\"events\": [], \"total_items\": 0}"),
+            ("POST", &delete, 204, ""),
+        ]);
+        let lists = Lists::new(server.api());
+        let (export, report) = lists
+            .erase_member("urist.mcvankab@freddiesjokes.com")
+            .unwrap();
+        assert!(export.failed.is_empty(), "{:?}", export.failed);
+        assert_eq!(export.list_ids(), vec!["57afe96172".to_string()]);
+        assert!(
+            server.requests().contains(&format!("POST {}", delete)),
+            "Se debe enviar la eliminación: {:?}",
+            server.requests()
+        );
+        assert_eq!(report.erased, vec!["57afe96172".to_string()]);
+        assert!(report.is_complete(), "{}", report.summary());
+        assert!(report.summary().contains("removed from 57afe96172"));
+
+        let empty_body = MockServer::start(vec![("POST", &delete, 200, "")]);
+        let mut member: ListMember = serde_json::from_str(&info).unwrap();
+        member.set_api(Rc::new(empty_body.api()));
+        assert!(member.permanently_delete().is_none());
+    }
+
+    #[test]
+    fn test_automation_removed_subscriber_and_email_update() {
+        let mock_transport = MockRequest::new(
+            "{\"id\": \"62eeb292278cc15f5817cb78f7790b08\",\"workflow_id\": \"b0a1c24f1a\",\"list_id\": \"57afe96172\",\"email_address\": \"urist.mcvankab@freddiesjokes.com\",\"_links\": []}",
+            "",
+        );
+        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let resp = api
+            .get_edge::<AutomationSubscriberType>(
+                "/automations/b0a1c24f1a/removed-subscribers/62eeb292278cc15f5817cb78f7790b08",
+                HashMap::new(),
+            )
+            .unwrap();
+        assert_eq!(resp.workflow_id, "b0a1c24f1a");
+        assert_eq!(resp.email_address, "urist.mcvankab@freddiesjokes.com");
+
+        let email: WorkflowEmailType =
+            serde_json::from_value(json!({"id": "491fec26f1", "status": "paused"})).unwrap();
+        assert!(
+            email.update(None, None).is_err(),
+            "Una actualización vacía debe fallar"
+        );
+
+        let server = MockServer::start(vec![
+            ("POST", "/automations/b0a1c24f1a/actions/archive", 204, ""),
+            (
+                "POST",
+                "/automations/b0a1c24f1a/removed-subscribers",
+                200,
+                "{\"id\": \"62eeb292278cc15f5817cb78f7790b08\",\"workflow_id\": \"b0a1c24f1a\",\"list_id\": \"57afe96172\",\"email_address\": \"urist.mcvankab@freddiesjokes.com\"}",
+            ),
+        ]);
+        let mut workflow: AutomationWorkflowType =
+            serde_json::from_value(json!({"id": "b0a1c24f1a", "status": "sending"})).unwrap();
+        workflow.set_api(Rc::new(server.api()));
+        let error = workflow.archive();
+        assert!(error.is_none(), "{:?}", error);
+        assert!(
+            workflow.get_subscriber_removed("../actions").is_err(),
+            "Un hash inválido no llega a enviarse"
+        );
+        #[allow(deprecated)]
+        let removed = workflow
+            .add_subscriber_to_workflow("urist.mcvankab@freddiesjokes.com")
+            .unwrap();
+        assert_eq!(removed.workflow_id, "b0a1c24f1a");
+        assert_eq!(
+            server.requests(),
+            vec![
+                "POST /automations/b0a1c24f1a/actions/archive".to_string(),
+                "POST /automations/b0a1c24f1a/removed-subscribers".to_string()
+            ],
+            "El método obsoleto elimina al suscriptor del workflow"
+        );
+    }
+
+    #[test]
+    fn test_campaign_builder_validation() {
+        let regular = CampaignBuilder::regular()
+            .list("57afe96172")
+            .segment(Segment::all().and(StaticSegment::member_of(49377)))
+            .subject_line("Your weekly jokes")
+            .from_name("Freddie")
+            .reply_to("freddie@freddiesjokes.com")
+            .build()
+            .unwrap();
+        let value = serde_json::to_value(&regular).unwrap();
+        assert_eq!(value["type"], json!("regular"));
+        assert_eq!(value["recipients"]["list_id"], json!("57afe96172"));
+        assert_eq!(
+            value["settings"]["reply_to"],
+            json!("freddie@freddiesjokes.com")
+        );
+        assert!(
+            value.get("rss_opts").is_none(),
+            "Solo se envían las opciones usadas"
+        );
+
+        let err = CampaignBuilder::regular()
+            .list("57afe96172")
+            .subject_line("Jokes")
+            .from_name("Freddie")
+            .reply_to("freddie")
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            CampaignBuildError::InvalidReplyTo("freddie".to_string())
+        );
+        assert!(err.to_string().contains("not an email address"), "{}", err);
+        assert!(CampaignBuilder::plaintext()
+            .subject_line("Jokes")
+            .from_name("Freddie")
+            .reply_to("freddie@freddiesjokes.com")
+            .validate()
+            .is_err());
+
+        let rss = CampaignBuilder::rss("https://freddiesjokes.com/feed", RssFrequency::Weekly)
+            .list("57afe96172")
+            .subject_line("*|RSSFEED:TITLE|*")
+            .from_name("Freddie")
+            .reply_to("freddie@freddiesjokes.com");
+        assert_eq!(
+            rss.validate(),
+            Err(CampaignBuildError::MissingRssWeeklySendDay)
+        );
+        let rss = rss.rss_schedule(SendingScheduleType {
+            hour: Some(9),
+            weekly_send_day: Some("monday".to_string()),
+            ..SendingScheduleType::default()
+        });
+        assert_eq!(rss.validate(), Ok(()));
+        let hourly = CampaignBuilder::rss("https://freddiesjokes.com/feed", "hourly".into())
+            .list("57afe96172")
+            .subject_line("*|RSSFEED:TITLE|*")
+            .from_name("Freddie")
+            .reply_to("freddie@freddiesjokes.com");
+        assert_eq!(
+            hourly.validate(),
+            Err(CampaignBuildError::InvalidRssFrequency)
+        );
+        let opts: RSSOptionsType = serde_json::from_str(r#"{"frequency": "monthly"}"#).unwrap();
+        assert_eq!(opts.frequency, Some(RssFrequency::Monthly));
+
+        let variate = CampaignBuilder::variate(VariateSettingsType {
+            winner_criteria: Some(WinnerCriteria::Opens),
+            subject_lines: Some(vec!["Jokes A".to_string(), "Jokes B".to_string()]),
+            test_size: Some(5),
+            ..VariateSettingsType::default()
+        })
+        .list("57afe96172")
+        .from_name("Freddie")
+        .reply_to("freddie@freddiesjokes.com");
+        assert_eq!(
+            variate.validate(),
+            Err(CampaignBuildError::Variate(VariateError::InvalidTestSize(
+                5
+            )))
+        );
+        assert_eq!(
+            CampaignBuilder::new(CampaignKind::Absplit).validate(),
+            Err(CampaignBuildError::UnsupportedKind(CampaignKind::Absplit))
+        );
+        let settings = VariateSettingsType {
+            subject_lines: Some(vec!["Jokes A".to_string(), "Jokes B".to_string()]),
+            test_size: Some(50),
+            ..VariateSettingsType::default()
+        };
+        let without_criteria = |settings: VariateSettingsType| {
+            CampaignBuilder::variate(settings)
+                .list("57afe96172")
+                .subject_line("Jokes")
+                .from_name("Freddie")
+                .reply_to("freddie@freddiesjokes.com")
+                .validate()
+        };
+        assert_eq!(
+            without_criteria(settings.clone()),
+            Err(CampaignBuildError::MissingWinnerCriteria)
+        );
+        let send_times = VariateSettingsType {
+            subject_lines: None,
+            send_times: Some(vec![
+                "2020-06-01T10:00:00+00:00".to_string(),
+                "2020-06-01T14:00:00+00:00".to_string(),
+            ]),
+            ..settings
+        };
+        assert_eq!(
+            without_criteria(send_times),
+            Ok(()),
+            "Al probar horas de envío no hace falta el criterio"
+        );
+        let error: MailchimpErrorType = CampaignBuildError::MissingList.into();
+        assert_eq!(error.detail, "the list of the campaign is required");
+    }
+
+    #[test]
+    fn test_compose_and_send_gating() {
+        let checklist: SendChecklistType = serde_json::from_value(json!({
+            "is_ready": true,
+            "items": [
+                {"type": "success", "id": 1, "heading": "List", "details": "ok"},
+                {"type": "warning", "id": 2, "heading": "Preview text", "details": "missing"}
+            ]
+        }))
+        .unwrap();
+        let content = CampaignContentParam {
+            plain_text: String::new(),
+            html: "<p>Hi *|FNAME|*</p>".to_string(),
+            url: String::new(),
+            template: TemplateContent::default(),
+            archive: UploadArchive::default(),
+            variate_contents: Vec::new(),
+        };
+        let plan = ComposeAndSend::new(content);
+        assert!(plan.blocking_items(&checklist).is_empty());
+        let strict = plan.clone().block_on_warnings(true);
+        assert_eq!(strict.blocking_items(&checklist)[0].heading, "Preview text");
+
+        let not_ready = SendChecklistType {
+            is_ready: false,
+            items: Vec::new(),
+            _links: Vec::new(),
+        };
+        assert_eq!(plan.blocking_items(&not_ready).len(), 1);
+
+        let mut result = ComposeAndSendResult::default();
+        result.done(WorkflowStepKind::Create, "campaign created");
+        result.failed(WorkflowStepKind::Content, "the content was rejected", None);
+        result.skip_remaining();
+        assert!(!result.is_success());
+        assert_eq!(
+            result.failed_step().map(|s| s.step),
+            Some(WorkflowStepKind::Content)
+        );
+        assert_eq!(result.steps.len(), 5, "Todos los pasos deben aparecer");
+        let value = serde_json::to_value(&result).unwrap();
+        assert_eq!(value["steps"][4]["step"], json!("delivery"));
+        assert_eq!(value["steps"][4]["status"], json!("skipped"));
+        assert!(result.summary().contains("Content: FAILED"));
+    }
+    #[test]
+    fn test_compose_and_send_run() {
+        let created = json!({"id": "c1", "type": "regular", "status": "save"}).to_string();
+        let ready = json!({"is_ready": true, "items": []}).to_string();
+        let blocked = json!({
+            "is_ready": false,
+            "items": [{"type": "error", "id": 1, "heading": "Subject line", "details": "missing"}]
+        })
+        .to_string();
+        let routes = |checklist: &str| {
+            MockServer::start(vec![
+                ("POST", "/campaigns", 200, &created),
+                (
+                    "PUT",
+                    "/campaigns/c1/content",
+                    200,
+                    "{\"html\": \"<p>Hi</p>\"}",
+                ),
+                ("GET", "/campaigns/c1/send-checklist", 200, checklist),
+                ("POST", "/campaigns/c1/actions/test", 204, ""),
+                ("POST", "/campaigns/c1/actions/send", 204, ""),
+            ])
+        };
+        let builder = || {
+            CampaignBuilder::regular()
+                .list("57afe96172")
+                .subject_line("Your weekly jokes")
+                .from_name("Freddie")
+                .reply_to("freddie@freddiesjokes.com")
+        };
+        let plan = ComposeAndSend::new(CampaignContent::html("<p>Hi</p>", None))
+            .test_emails(vec!["qa@freddiesjokes.com".to_string()], "html");
+
+        let server = routes(&ready);
+        let result = Campaigns::new(server.api()).compose_and_send(builder(), &plan);
+        let statuses: Vec<(WorkflowStepKind, WorkflowStepStatus)> =
+            result.steps.iter().map(|s| (s.step, s.status)).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (WorkflowStepKind::Create, WorkflowStepStatus::Done),
+                (WorkflowStepKind::Content, WorkflowStepStatus::Done),
+                (WorkflowStepKind::Checklist, WorkflowStepStatus::Done),
+                (WorkflowStepKind::TestEmail, WorkflowStepStatus::Done),
+                (WorkflowStepKind::Delivery, WorkflowStepStatus::Done),
+            ],
+            "{}",
+            result.summary()
+        );
+        assert!(result.is_success());
+        assert_eq!(result.campaign_id, Some("c1".to_string()));
+        assert_eq!(
+            server.requests(),
+            vec![
+                "POST /campaigns",
+                "PUT /campaigns/c1/content",
+                "GET /campaigns/c1/send-checklist",
+                "POST /campaigns/c1/actions/test",
+                "POST /campaigns/c1/actions/send",
+            ]
+        );
+        assert_eq!(
+            server.body_of("POST /campaigns/c1/actions/test"),
+            json!({"test_emails": ["qa@freddiesjokes.com"], "send_type": "html"})
+        );
+
+        let server = routes(&blocked);
+        let result = Campaigns::new(server.api()).compose_and_send(builder(), &plan);
+        let statuses: Vec<WorkflowStepStatus> = result.steps.iter().map(|s| s.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                WorkflowStepStatus::Done,
+                WorkflowStepStatus::Done,
+                WorkflowStepStatus::Failed,
+                WorkflowStepStatus::Skipped,
+                WorkflowStepStatus::Skipped,
+            ]
+        );
+        assert_eq!(result.blocking_items[0].heading, "Subject line");
+        assert_eq!(server.requests().len(), 3, "No se envía nada");
+    }
+    #[test]
+    fn test_compose_and_send_schedule() {
+        let created = json!({"id": "c1", "type": "regular", "status": "save"}).to_string();
+        let ready = json!({"is_ready": true, "items": []}).to_string();
+        let server = || {
+            MockServer::start(vec![
+                ("POST", "/campaigns", 200, &created),
+                ("PUT", "/campaigns/c1/content", 200, "{}"),
+                ("GET", "/campaigns/c1/send-checklist", 200, &ready),
+                ("POST", "/campaigns/c1/actions/schedule", 204, ""),
+            ])
+        };
+        let builder = || {
+            CampaignBuilder::regular()
+                .list("57afe96172")
+                .subject_line("Your weekly jokes")
+                .from_name("Freddie")
+                .reply_to("freddie@freddiesjokes.com")
+        };
+        let plan = |schedule: CampaignSchedule| {
+            ComposeAndSend::new(CampaignContent::html("<p>Hi</p>", None))
+                .delivery(CampaignDelivery::Schedule(schedule))
+        };
+
+        let at = ScheduleTime::from_unix(ScheduleTime::now().unix() + 3 * 86_400)
+            .round(QuarterHourRule::RoundUp);
+        let ok = server();
+        let result = Campaigns::new(ok.api())
+            .compose_and_send(builder(), &plan(CampaignSchedule::at(at).timewarp()));
+        assert!(result.is_success(), "{}", result.summary());
+        assert_eq!(
+            ok.body_of("POST /campaigns/c1/actions/schedule"),
+            json!({"schedule_time": at.to_iso8601(), "timewarp": true})
+        );
+        assert!(result
+            .summary()
+            .ends_with(&format!("scheduled for {}", at.to_iso8601())));
+
+        let soon = ScheduleTime::from_unix(ScheduleTime::now().unix() + 12 * 3600)
+            .round(QuarterHourRule::RoundUp);
+        let rejected = server();
+        let result = Campaigns::new(rejected.api())
+            .compose_and_send(builder(), &plan(CampaignSchedule::at(soon).timewarp()));
+        assert_eq!(
+            result.failed_step().map(|s| s.step),
+            Some(WorkflowStepKind::Delivery),
+            "Timewarp necesita 24 horas de antelación"
+        );
+        assert!(!rejected
+            .requests()
+            .contains(&"POST /campaigns/c1/actions/schedule".to_string()));
+    }
+
+    #[test]
+    fn test_campaign_schedule_rules() {
+        let now = ScheduleTime::parse("2020-06-01T10:00:00Z").unwrap();
+        let at = ScheduleTime::parse("2020-06-01 14:07:30.250+02:00").unwrap();
+        assert_eq!(at.to_iso8601(), "2020-06-01T12:07:30+00:00");
+        assert_eq!(
+            ScheduleTime::parse("2020-06-01T14:00:00"),
+            Err(ScheduleError::MissingTimezone(
+                "2020-06-01T14:00:00".to_string()
+            ))
+        );
+        assert!(ScheduleTime::parse("2020-13-01T14:00:00Z").is_err());
+        assert_eq!(
+            ScheduleTime::parse("2021-02-31T10:00:00Z"),
+            Err(ScheduleError::InvalidTime(
+                "2021-02-31T10:00:00Z".to_string()
+            ))
+        );
+        assert!(ScheduleTime::parse("2021-02-29T10:00:00Z").is_err());
+        assert!(ScheduleTime::parse("2020-04-31T10:00:00Z").is_err());
+        assert_eq!(
+            ScheduleTime::parse("2020-02-29T10:00:00Z")
+                .unwrap()
+                .to_iso8601(),
+            "2020-02-29T10:00:00+00:00"
+        );
+        assert!(ScheduleTime::parse("2100-02-29T10:00:00Z").is_err());
+        assert!(ScheduleTime::parse("2000-02-29T10:00:00Z").is_ok());
+        assert_eq!(
+            at.round(QuarterHourRule::RoundDown).to_iso8601(),
+            "2020-06-01T12:00:00+00:00"
+        );
+        assert_eq!(
+            at.round(QuarterHourRule::RoundNearest).to_iso8601(),
+            "2020-06-01T12:15:00+00:00"
+        );
+
+        match CampaignSchedule::at(at).build_at(now) {
+            Err(ScheduleError::NotQuarterHour(_)) => {}
+            other => panic!("Se esperaba NotQuarterHour: {:?}", other),
+        }
+        let param = CampaignSchedule::at(at)
+            .rounding(QuarterHourRule::RoundUp)
+            .batch_delivery(2, 15)
+            .build_at(now)
+            .unwrap();
+        assert_eq!(param.schedule_time, "2020-06-01T12:15:00+00:00");
+        assert_eq!(param.batch_delivery.unwrap().batch_count, 2);
+
+        let quarter = ScheduleTime::parse("2020-06-01T12:15:00Z").unwrap();
+        assert_eq!(
+            CampaignSchedule::at(quarter)
+                .timewarp()
+                .batch_delivery(2, 15)
+                .build_at(now)
+                .unwrap_err(),
+            ScheduleError::TimewarpWithBatchDelivery
+        );
+        assert_eq!(
+            CampaignSchedule::at(quarter)
+                .timewarp()
+                .build_at(now)
+                .unwrap_err(),
+            ScheduleError::TimewarpLeadTime(quarter)
+        );
+        let tomorrow = ScheduleTime::parse("2020-06-02T10:00:00Z").unwrap();
+        let param = CampaignSchedule::at(tomorrow)
+            .timewarp()
+            .build_at(now)
+            .unwrap();
+        assert!(param.timewarp);
+        assert!(serde_json::to_value(&param)
+            .unwrap()
+            .get("batch_delivery")
+            .is_none());
+        assert_eq!(
+            CampaignSchedule::at(now).build_at(now).unwrap_err(),
+            ScheduleError::InThePast(now)
+        );
+        let err: MailchimpErrorType = ScheduleError::TimewarpWithBatchDelivery.into();
+        assert_eq!(err.status, 400);
+    }
+
+    #[test]
+    fn test_campaign_content_modes() {
+        let to_json = |content: CampaignContent| {
+            serde_json::to_value(CampaignContentParam::from(content)).unwrap()
+        };
+        assert_eq!(
+            to_json(CampaignContent::html("<p>Hi</p>", Some("Hi"))),
+            json!({"html": "<p>Hi</p>", "plain_text": "Hi"})
+        );
+        assert_eq!(
+            to_json(CampaignContent::url("https://freddiesjokes.com/newsletter")),
+            json!({"url": "https://freddiesjokes.com/newsletter"})
+        );
+        assert_eq!(
+            to_json(CampaignContent::template(
+                2000020,
+                vec![("header", "<h1>Jokes</h1>")]
+            )),
+            json!({"template": {"id": 2000020, "sections": {"header": "<h1>Jokes</h1>"}}})
+        );
+        let variate = to_json(CampaignContent::variate(vec![VariateContent {
+            content_label: "A".to_string(),
+            html: "<p>A</p>".to_string(),
+            ..VariateContent::default()
+        }]));
+        assert_eq!(
+            variate,
+            json!({"variate_contents": [{"content_label": "A", "html": "<p>A</p>"}]})
+        );
+
+        let archive = UploadArchive::from_bytes(b"PK", "zip");
+        assert_eq!(archive.archive_content, "UEs=");
+        let dir = std::env::temp_dir().join(format!("mailchimp-content-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("images")).unwrap();
+        std::fs::write(dir.join("content.tar.gz"), b"PK").unwrap();
+        match CampaignContent::archive_file(dir.join("content.tar.gz")).unwrap() {
+            CampaignContent::Archive(a) => assert_eq!(a.archive_type, "tar.gz"),
+            other => panic!("Se esperaba un archivo: {:?}", other),
+        }
+        assert!(CampaignContent::archive_file(dir.join("content.rar")).is_err());
+
+        #[cfg(feature = "zip")]
+        {
+            std::fs::remove_file(dir.join("content.tar.gz")).unwrap();
+            std::fs::write(dir.join("index.html"), "<img src=\"images/logo.png\">").unwrap();
+            std::fs::write(dir.join("images").join("logo.png"), [0u8, 1, 2]).unwrap();
+            let archive = UploadArchive::from_dir(&dir).unwrap();
+            assert_eq!(archive.archive_type, "zip");
+            let bytes = base64::decode(&archive.archive_content).unwrap();
+            let mut zip = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+            let mut names: Vec<String> = (0..zip.len())
+                .map(|i| zip.by_index(i).unwrap().name().to_string())
+                .collect();
+            names.sort();
+            assert_eq!(names, vec!["images/logo.png", "index.html"]);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let server = MockServer::start(vec![(
+            "PUT",
+            "/campaigns/c1/content",
+            200,
+            "{\"html\": \"<p>Hi</p>\"}",
+        )]);
+        let mut campaign: CampaignType = serde_json::from_value(json!({"id": "c1"})).unwrap();
+        campaign.set_api(Rc::new(server.api()));
+        let content = campaign
+            .set_content(CampaignContent::html("<p>Hi</p>", None))
+            .unwrap();
+        assert_eq!(content.html, "<p>Hi</p>");
+        assert_eq!(server.requests(), vec!["PUT /campaigns/c1/content"]);
+        assert_eq!(
+            server.body_of("PUT /campaigns/c1/content"),
+            json!({"html": "<p>Hi</p>"})
+        );
+    }
+
+    #[test]
+    fn test_merge_tag_lint_and_plain_text() {
+        let html = "<html><head><title>News</title><style>p {color: red}</style></head>\
+            <body><h1>Hi *|FNAME|*</h1><p>Our&nbsp;jokes &amp; more:</p>\
+            <ul><li>One</li><li>Two</li></ul>\
+            <p><a href=\"https://freddiesjokes.com\">Visit us</a></p>\
+            <p><a href=\"*|UNSUB|*\">Unsubscribe</a></p></body></html>";
+        assert_eq!(
+            html_to_text(html),
+            "Hi *|FNAME|*\n\nOur jokes & more:\n\n* One\n* Two\n\n\
+             Visit us (https://freddiesjokes.com)\n\nUnsubscribe (*|UNSUB|*)"
+        );
+        for n in [10, 20] {
+            let html = format!("<STYLE>{}</Style>€€€<p>x</p>", "İ".repeat(n));
+            assert_eq!(html_to_text(&html), "€€€\n\nx", "{} veces İ", n);
+        }
+        assert_eq!(
+            html_to_text("<a title=\"İİİİ\" HREF=\"https://freddiesjokes.com\">Visit</a>"),
+            "Visit (https://freddiesjokes.com)"
+        );
+
+        let fields: Vec<ListMergeField> =
+            serde_json::from_value(json!([{"merge_id": 1, "tag": "FNAME"}])).unwrap();
+        assert!(lint_merge_tags(html, &fields).is_empty());
+
+        let content = "*|IF:FNAME=Freddie|*\n*|NICKNAME|*\n*|ELSE:|*\n*|MERGE1|* *|LIST:NAME|*\n\
+            *|INTERESTED:Jokes|*\n*|END:IF|*";
+        let issues = lint_merge_tags(content, &fields);
+        assert_eq!(
+            issues,
+            vec![
+                MergeTagIssue::UnknownTag {
+                    tag: "NICKNAME".to_string(),
+                    line: 2
+                },
+                MergeTagIssue::UnbalancedConditional {
+                    tag: "END:IF".to_string(),
+                    line: 6,
+                    reason: "closes the block *|INTERESTED:Jokes|* opened on line 5".to_string()
+                },
+                MergeTagIssue::UnbalancedConditional {
+                    tag: "IF:FNAME=Freddie".to_string(),
+                    line: 1,
+                    reason: "is never closed".to_string()
+                },
+                MergeTagIssue::UnbalancedConditional {
+                    tag: "INTERESTED:Jokes".to_string(),
+                    line: 5,
+                    reason: "is never closed".to_string()
+                },
+                MergeTagIssue::MissingUnsubscribe,
+            ],
+            "Problemas inesperados en las etiquetas"
+        );
+        assert_eq!(
+            lint_merge_tags("*|END:IF|* *|IF:LNAME|* *|END:IF|* *|UNSUB|*", &fields).len(),
+            2,
+            "Se esperaba un bloque sin abrir y un campo desconocido"
+        );
+        let interested =
+            "*|INTERESTED:Topics:Puns|*puns*|ELSE:|*no puns*|END:INTERESTED|* *|UNSUB|*";
+        assert!(
+            lint_merge_tags(interested, &fields).is_empty(),
+            "ELSE es válido dentro de INTERESTED"
+        );
+        assert_eq!(
+            lint_merge_tags(
+                "*|INTERESTED:Topics:Puns|*a*|ELSE:|*b*|ELSE:|*c*|END:INTERESTED|* *|UNSUB|*",
+                &fields
+            ),
+            vec![MergeTagIssue::UnbalancedConditional {
+                tag: "ELSE:".to_string(),
+                line: 1,
+                reason: "comes after another *|ELSE:|*".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_merge_renderer() {
+        let member: ListMember = serde_json::from_value(json!({
+            "email_address": "freddie@freddiesjokes.com",
+            "merge_fields": {"FNAME": "Freddie", "LNAME": "", "PLAN": "gold", "POINTS": 120},
+            "interests": {"a1": false, "b2": true}
+        }))
+        .unwrap();
+        let fields: Vec<ListMergeField> = serde_json::from_value(json!([
+            {"merge_id": 1, "tag": "FNAME"},
+            {"merge_id": 2, "tag": "LNAME", "default_value": "Friend"}
+        ]))
+        .unwrap();
+        let list: ListType = serde_json::from_value(json!({
+            "name": "Freddie's Jokes",
+            "contact": {"company": "MailChimp", "address1": "675 Ponce De Leon Ave NE", "city": "Atlanta"}
+        }))
+        .unwrap();
+        let renderer = MergeRenderer::new()
+            .list(&list)
+            .merge_fields(fields)
+            .interest("Topics", "Puns", "a1")
+            .interest("Topics", "Knock knock", "b2")
+            .tag("UNSUB", "https://example.com/unsub");
+
+        let html = "*|MERGE1|* *|LNAME|* <*|EMAIL|*>\n\
+            *|IF:PLAN=silver|*silver*|ELSEIF:PLAN=Gold|*gold*|ELSE:|*free*|END:IF|*\n\
+            *|IF:POINTS>=100|**|IFNOT:NICKNAME|*no nickname*|END:IF|**|END:IF|*\n\
+            *|INTERESTED:Topics:Puns|*puns*|ELSE:|*no puns*|END:INTERESTED|*\n\
+            *|INTERESTED:Topics:Puns,Knock knock|*jokes*|END:INTERESTED|*\n\
+            *|LIST:NAME|*, *|LIST:ADDRESSLINE|* *|UNSUB|* *|ARCHIVE|*";
+        assert_eq!(
+            renderer.render(html, &member).unwrap(),
+            "Freddie Friend <freddie@freddiesjokes.com>\ngold\nno nickname\nno puns\njokes\n\
+             Freddie's Jokes, 675 Ponce De Leon Ave NE, Atlanta https://example.com/unsub *|ARCHIVE|*",
+            "El contenido renderizado no coincide"
+        );
+        assert_eq!(
+            renderer.render("<b>*|FNAME|*</b>", &member).unwrap(),
+            "<b>Freddie</b>"
+        );
+        assert_eq!(
+            renderer.render("*|ıı:éé|*", &member).unwrap(),
+            "*|ıı:éé|*",
+            "Las etiquetas desconocidas no se modifican"
+        );
+        let renderer = renderer.tag("LIST:NAME", "Tom & Jerry");
+        assert_eq!(
+            renderer.render("*|LIST:NAME|*", &member).unwrap(),
+            "Tom &amp; Jerry"
+        );
+        assert_eq!(
+            renderer
+                .clone()
+                .escape_html(false)
+                .render("*|LIST:NAME|*", &member)
+                .unwrap(),
+            "Tom & Jerry"
+        );
+        match renderer.render("*|IF:FNAME|*x*|END:INTERESTED|*", &member) {
+            Err(MergeTagIssue::UnbalancedConditional { line, .. }) => assert_eq!(line, 1),
+            other => panic!("Se esperaba un bloque sin cerrar: {:?}", other),
+        }
+        assert!(renderer.render("*|IF:FNAME|*x", &member).is_err());
+    }
+
+    #[test]
+    fn test_variate_config_and_report() {
+        let config = VariateConfig::new()
+            .subject_line("A")
+            .subject_line("B")
+            .from_name("Freddie", "freddie@freddiesjokes.com")
+            .from_name("Jokes Team", "team@freddiesjokes.com")
+            .winner_criteria(WinnerCriteria::TotalRevenue)
+            .test_size(20);
+        assert_eq!(config.combination_count(), 4);
+        let settings = config.build().unwrap();
+        assert_eq!(
+            serde_json::to_value(&settings).unwrap(),
+            json!({
+                "winner_criteria": "total_revenue",
+                "test_size": 20,
+                "subject_lines": ["A", "B"],
+                "from_names": ["Freddie", "Jokes Team"],
+                "reply_to_addresses": ["freddie@freddiesjokes.com", "team@freddiesjokes.com"]
+            })
+        );
+
+        let too_many = config
+            .clone()
+            .content("Long")
+            .content("Short")
+            .content("Medium");
+        assert_eq!(
+            too_many.build().unwrap_err(),
+            VariateError::TooManyCombinations(12)
+        );
+        let four_subjects = VariateConfig::new()
+            .subject_line("A")
+            .subject_line("B")
+            .subject_line("C")
+            .subject_line("D");
+        match four_subjects.build() {
+            Err(VariateError::TooManyVariations { count, .. }) => assert_eq!(count, 4),
+            other => panic!("Se esperaba demasiadas variaciones: {:?}", other),
+        }
+        assert_eq!(
+            VariateConfig::new().subject_line("A").build().unwrap_err(),
+            VariateError::NothingToTest
+        );
+        assert_eq!(
+            config.clone().wait_hours(0).build().unwrap_err(),
+            VariateError::InvalidWaitTime(0)
+        );
+
+        let send_times = VariateConfig::new()
+            .send_time(ScheduleTime::parse("2020-06-01T10:00:00Z").unwrap())
+            .send_time(ScheduleTime::parse("2020-06-01T14:30:00Z").unwrap())
+            .winner_criteria(WinnerCriteria::Opens)
+            .test_size(50)
+            .build()
+            .unwrap();
+        assert_eq!(send_times.test_size, Some(100));
+        assert_eq!(send_times.winner_criteria, None);
+        assert_eq!(
+            send_times.send_times.unwrap()[1],
+            "2020-06-01 14:30:00".to_string()
+        );
+
+        let settings: VariateSettingsType = serde_json::from_value(json!({
+            "winning_combination_id": "c2",
+            "winning_campaign_id": "w1",
+            "winner_criteria": "clicks",
+            "subject_lines": ["A", "B"],
+            "contents": ["Long", "Short"],
+            "combinations": [
+                {"id": "c1", "subject_line": 0, "content_description": 1, "recipients": 100},
+                {"id": "c2", "subject_line": 1, "content_description": 0, "recipients": 98}
+            ]
+        }))
+        .unwrap();
+        let report: ReportType = serde_json::from_value(json!({
+            "id": "42694e9e57",
+            "emails_sent": 198,
+            "opens": {"unique_opens": 80, "open_rate": 0.4},
+            "clicks": {"unique_clicks": 20, "click_rate": 0.1}
+        }))
+        .unwrap();
+        let variate = VariateReport::new(&settings, &report);
+        assert_eq!(variate.winner_criteria, Some(WinnerCriteria::Clicks));
+        assert_eq!(variate.combinations.len(), 2);
+        assert_eq!(variate.combinations[0].content.as_deref(), Some("Short"));
+        let winner = variate.winner().expect("Se esperaba un ganador");
+        assert_eq!(winner.id, "c2");
+        assert_eq!(winner.subject_line.as_deref(), Some("B"));
+        assert_eq!(winner.recipients, 98);
+        assert_eq!(variate.unique_clicks, 20);
+    }
 
+    #[test]
+    fn test_report_export() {
+        let report: ReportType = serde_json::from_value(json!({
+            "id": "42694e9e57",
+            "campaign_title": "Jokes, week 1",
+            "type": "regular",
+            "list_id": "57afe96172",
+            "emails_sent": 200,
+            "unsubscribed": 2,
+            "send_time": "2020-06-01T10:00:00+00:00",
+            "bounces": {"hard_bounces": 3, "soft_bounces": 1},
+            "opens": {"unique_opens": 50, "open_rate": 0.25},
+            "clicks": {"unique_clicks": 10, "click_rate": 0.05},
+            "list_stats": {"open_rate": 20.0, "click_rate": 0.0},
+            "industry_stats": {"type": "Entertainment", "open_rate": 0.2, "bounce_rate": 0.01, "unsub_rate": 0.005}
+        }))
+        .unwrap();
+        let row = ReportRow::from_report(&report, true);
+        assert_eq!(row.bounce_rate, 0.02);
+        assert_eq!(row.unsub_rate, 0.01);
+        let deltas = row.deltas.clone().unwrap();
+        assert_eq!(deltas.open_rate_vs_list, Some(5.0));
+        assert_eq!(deltas.click_rate_vs_list, None, "Sin promedio de la lista");
+        assert_eq!(deltas.open_rate_vs_industry, Some(5.0));
+        assert_eq!(deltas.bounce_rate_vs_industry, Some(1.0));
+        assert_eq!(deltas.unsub_rate_vs_industry, Some(0.5));
+
+        let mut csv = Vec::new();
+        write_reports_csv(std::slice::from_ref(&row), &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("id,campaign_title,campaign_type,list_id"));
+        assert!(lines[0].ends_with("bounce_rate_vs_industry,unsub_rate_vs_industry"));
+        assert!(lines[1].starts_with("42694e9e57,\"Jokes, week 1\",regular,57afe96172"));
+        assert_eq!(
+            lines[0].split(',').count(),
+            row.columns().len(),
+            "La cabecera no coincide con las columnas"
+        );
+        let mut formula = row.clone();
+        formula.campaign_title = "=HYPERLINK(\"http://evil.example\")".to_string();
+        let mut csv = Vec::new();
+        write_reports_csv(&[formula], &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert!(
+            csv.lines()
+                .nth(1)
+                .unwrap()
+                .starts_with("42694e9e57,\"'=HYPERLINK(\"\"http://evil.example\"\")\",regular"),
+            "El título no se debe ejecutar como fórmula: {}",
+            csv
+        );
+
+        let mut jsonl = Vec::new();
+        write_reports_jsonl(&[row, ReportRow::from(&report)], &mut jsonl).unwrap();
+        let jsonl = String::from_utf8(jsonl).unwrap();
+        let objects: Vec<serde_json::Value> = jsonl
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects[0]["open_rate_vs_list"], json!(5.0));
+        assert_eq!(objects[0]["send_time"], json!("2020-06-01T10:00:00+00:00"));
+        assert!(objects[1].get("open_rate_vs_list").is_none());
+    }
+
+    #[test]
+    fn test_analytics_series() {
+        let report: ReportType = serde_json::from_value(json!({
+            "id": "42694e9e57",
+            "timeseries": [
+                {"timestamp": "2020-06-01T11:00:00+00:00", "emails_sent": 0, "unique_opens": 30, "recipients_clicks": 5},
+                {"timestamp": "2020-06-01T10:00:00+00:00", "emails_sent": 200, "unique_opens": 20, "recipients_clicks": 2},
+                {"timestamp": "2020-06-01T12:00:00+00:00", "emails_sent": 0, "unique_opens": 50, "recipients_clicks": 3},
+                {"timestamp": "", "emails_sent": 0, "unique_opens": 99, "recipients_clicks": 0}
+            ],
+            "timewarp": [
+                {"gmt_offset": 2, "opens": 4, "last_open": "2020-06-01T12:00:00+00:00"},
+                {"gmt_offset": -5, "opens": 7}
+            ]
+        }))
+        .unwrap();
+        let series = CampaignTimeSeries::from_report(&report);
+        assert_eq!(
+            series.unique_opens.points.len(),
+            3,
+            "Se ignora la fecha vacía"
+        );
+        let opens: Vec<u64> = series
+            .cumulative_opens()
+            .points
+            .iter()
+            .map(|p| p.value)
+            .collect();
+        assert_eq!(opens, vec![20, 50, 100]);
+        assert_eq!(
+            series.cumulative_clicks().points.last().map(|p| p.value),
+            Some(series.recipients_clicks.total())
+        );
+        assert_eq!(
+            series.time_to_half_opens(),
+            Some(std::time::Duration::from_secs(2 * 3600))
+        );
+        assert_eq!(
+            series.unique_opens.points[0].time.to_iso8601(),
+            "2020-06-01T10:00:00+00:00"
+        );
+
+        let at = |t: &str| parse_instant(t).unwrap();
+        let weekly = TimeSeries::new(vec![
+            SeriesPoint::new(at("2020-06-07 23:00:00"), 1),
+            SeriesPoint::new(at("2020-06-08"), 2),
+            SeriesPoint::new(at("2020-06-21T10:00:00+02:00"), 3),
+        ])
+        .resample(Bucket::Weekly);
+        let weeks: Vec<(String, u64)> = weekly
+            .points
+            .iter()
+            .map(|p| (p.time.to_iso8601()[..10].to_string(), p.value))
+            .collect();
+        assert_eq!(
+            weeks,
+            vec![
+                ("2020-06-01".to_string(), 1),
+                ("2020-06-08".to_string(), 2),
+                ("2020-06-15".to_string(), 3)
+            ]
+        );
+
+        let timewarp = timewarp_series(&report.timewarp);
+        assert_eq!(timewarp[0].gmt_offset, -5);
+        assert_eq!(timewarp[1].last_open, Some(at("2020-06-01T12:00:00Z")));
+        #[cfg(feature = "chrono")]
+        {
+            use chrono::{TimeZone, Utc};
+
+            assert_eq!(
+                timewarp[1].last_open_utc(),
+                Some(Utc.with_ymd_and_hms(2020, 6, 1, 12, 0, 0).unwrap())
+            );
+            assert_eq!(timewarp[0].last_click_utc(), None);
+            assert_eq!(
+                series.unique_opens.points[0].time_utc(),
+                parse_timestamp("2020-06-01T10:00:00+00:00")
+            );
+        }
+
+        let history: Vec<ListGrowthHistoryType> = serde_json::from_value(json!([
+            {"month": "2020-03", "subscribed": 10, "unsubscribed": 1, "cleaned": 1},
+            {"month": "2020-01", "subscribed": 100, "existing": 500},
+            {"month": "2020-02", "subscribed": 0, "unsubscribed": 100}
+        ]))
+        .unwrap();
+        let growth = list_growth(&history);
+        assert_eq!(
+            growth.iter().map(|g| (g.year, g.month)).collect::<Vec<_>>(),
+            vec![(2020, 1), (2020, 2), (2020, 3)]
+        );
+        assert_eq!(growth[0].growth_rate, None);
+        assert_eq!(growth[0].added, None);
+        assert_eq!(growth[1].growth_rate, Some(-1.0));
+        assert_eq!(growth[1].churn_rate, Some(1.0));
+        assert_eq!(growth[1].added, Some(0));
+        assert_eq!(growth[2].growth_rate, None, "Sin miembros el mes anterior");
+        assert_eq!(growth[2].added, Some(12));
+        assert_eq!(growth[2].lost, 2);
+    }
+
+    #[test]
+    fn test_landing_pages() {
+        let param = LandingPageParam::new("Signup", "a1b2c3", 42);
+        assert!(param.validate_create().is_ok(), "Parámetros válidos");
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            json!({"name": "Signup", "list_id": "a1b2c3", "type": "signup", "template_id": 42}),
+            "Serialización de los parámetros"
+        );
+        let mut missing = param.clone();
+        missing.template_id = None;
+        assert!(missing.validate_create().is_err(), "Falta la plantilla");
+
+        let page: LandingPage =
+            serde_json::from_value(json!({"id": "p1", "status": "published", "template_id": 42}))
+                .unwrap();
+        assert!(
+            page.update(param).is_err(),
+            "La plantilla no se puede actualizar"
+        );
+
+        let content: LandingPageContent =
+            serde_json::from_value(json!({"html": "<p>Hi</p>", "json": "{}"})).unwrap();
+        assert_eq!(content.html, "<p>Hi</p>", "Contenido HTML");
+
+        let reports: CollectionLandingPageReport = serde_json::from_value(json!({
+            "landing_pages": [{
+                "id": "p1", "visits": 10, "unique_visits": 8, "subscribes": 2,
+                "conversion_rate": 25.0,
+                "signup_tags": [{"tag_id": 7, "tag_name": "lp"}],
+                "ecommerce": {"total_revenue": 9.5, "currency_code": "USD", "total_orders": 1}
+            }],
+            "total_items": 1
+        }))
+        .unwrap();
+        let report = &reports.landing_pages[0];
+        assert_eq!(report.unique_visits, 8, "Visitas únicas");
+        assert_eq!(report.signup_tags[0].tag_name, "lp", "Etiquetas");
+        assert_eq!(report.ecommerce.total_orders, 1, "Pedidos");
+    }
+    #[test]
+    fn test_landing_page_actions() {
+        let created =
+            json!({"id": "p1", "name": "Signup", "status": "draft", "template_id": 42}).to_string();
+        let content = json!({"html": "<p>Hi</p>", "json": "{}"}).to_string();
+        let server = MockServer::start(vec![
+            ("POST", "/landing-pages", 200, &created),
+            ("POST", "/landing-pages/p1/actions/publish", 204, ""),
+            ("POST", "/landing-pages/p1/actions/unpublish", 204, ""),
+            ("GET", "/landing-pages/p1/content", 200, &content),
+        ]);
+        let pages = LandingPages::new(server.api());
+        let page = pages
+            .create(LandingPageParam::new("Signup", "a1b2c3", 42))
+            .unwrap();
+        assert_eq!(page.id, "p1", "Página creada");
+        let error = page.publish();
+        assert!(error.is_none(), "{:?}", error);
+        let error = page.unpublish();
+        assert!(error.is_none(), "{:?}", error);
+        assert_eq!(page.get_content().unwrap().html, "<p>Hi</p>");
+        assert_eq!(
+            server.requests(),
+            vec![
+                "POST /landing-pages",
+                "POST /landing-pages/p1/actions/publish",
+                "POST /landing-pages/p1/actions/unpublish",
+                "GET /landing-pages/p1/content",
+            ]
+        );
+    }
 }
@@ -193,7 +193,7 @@ impl HttpReq for MailchimpRequest {
             Some(auth) => CLIENT
                 .patch(url)
                 .basic_auth(auth.username.clone(), Some(auth.api_token.clone())),
-            None => CLIENT.post(url),
+            None => CLIENT.patch(url),
         };
         let result = builder.headers(headers).json(&payload).send();
         self.process_response(result, "PATCH")
@@ -216,9 +216,9 @@ impl HttpReq for MailchimpRequest {
     {
         let builder = match basic_auth {
             Some(auth) => CLIENT
-                .patch(url)
+                .put(url)
                 .basic_auth(auth.username.clone(), Some(auth.api_token.clone())),
-            None => CLIENT.post(url),
+            None => CLIENT.put(url),
         };
         let result = builder.headers(headers).json(&payload).send();
         self.process_response(result, "PUT")
@@ -238,7 +238,7 @@ impl HttpReq for MailchimpRequest {
             Some(auth) => CLIENT
                 .delete(url)
                 .basic_auth(auth.username.clone(), Some(auth.api_token.clone())),
-            None => CLIENT.get(url),
+            None => CLIENT.delete(url),
         };
 
         let result = builder.headers(headers).send();
@@ -254,7 +254,10 @@ impl MailchimpRequest {
     ) -> MailchimpResult<String> {
         match response {
             Ok(resp) => match resp.status() {
-                StatusCode::OK => match resp.text() {
+                // Las acciones responden 204 sin contenido
+                StatusCode::NO_CONTENT => Ok("{}".to_string()),
+                status if status.is_success() => match resp.text() {
+                    Ok(txt) if txt.trim().is_empty() => Ok("{}".to_string()),
                     Ok(txt) => Ok(txt),
                     Err(e) => {
                         error!(target: "mailchimp", "{:?}: Response Error Details: {:?}", method, e);
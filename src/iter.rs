@@ -2,6 +2,7 @@
 //!
 
 use crate::api::MailchimpApi;
+use crate::internal::request::MailchimpResult;
 use std::rc::Rc;
 use log::error;
 use serde::de::DeserializeOwned;
@@ -192,3 +193,38 @@ where
         }
     }
 }
+
+///
+/// Read every page of a collection starting at `filters`. Unlike
+/// `MalchimpIter`, the first failed request is returned instead of ending the
+/// collection early, use it when a partial result is not acceptable.
+///
+pub(crate) fn fetch_all<B>(
+    builder: &B,
+    api: Rc<MailchimpApi>,
+    endpoint: &str,
+    filters: B::FilterItem,
+) -> MailchimpResult<Vec<B::Item>>
+where
+    B: BuildIter,
+    B::FilterItem: ResourceFilter,
+    B::Collection: MailchimpCollection<B::Item> + DeserializeOwned,
+{
+    let mut items = Vec::new();
+    let mut filters = filters;
+    loop {
+        let collection = api.get::<B::Collection>(endpoint, filters.build_payload())?;
+        let values = collection.get_values();
+        if values.is_empty() {
+            break;
+        }
+        for value in &values {
+            items.push(builder.update_item(value, api.clone()));
+        }
+        if items.len() as u64 >= collection.get_total_items() {
+            break;
+        }
+        filters = builder.update_filter_offset(&filters);
+    }
+    Ok(items)
+}
@@ -1,7 +1,8 @@
 use super::api::MailchimpApi;
 use super::internal::request::MailchimpResult;
 use super::types::{
-    CollectionLandingPage, LandingPageBuilder, LandingPage
+    CollectionLandingPage, CollectionLandingPageReport, LandingPage, LandingPageBuilder,
+    LandingPageParam, LandingPageReport, LandingPageReportBuilder,
 };
 use crate::iter::{MalchimpIter, SimpleFilter, ResourceFilter};
 use log::error;
@@ -47,7 +48,9 @@ impl LandingPages {
             .get::<CollectionLandingPage>(&endpoint, filter_params.build_payload())
         {
             Ok(collection) => MalchimpIter {
-                builder: LandingPageBuilder {},
+                builder: LandingPageBuilder {
+                    endpoint: endpoint.to_string(),
+                },
                 data: collection.landing_pages,
                 cur_filters: filter_params.clone(),
                 cur_it: 0,
@@ -58,7 +61,9 @@ impl LandingPages {
             Err(e) => {
                 error!( target: "mailchimp",  "Get Activities: Response Error details: {:?}", e);
                 MalchimpIter {
-                    builder: LandingPageBuilder {},
+                    builder: LandingPageBuilder {
+                        endpoint: endpoint.to_string(),
+                    },
                     data: Vec::new(),
                     cur_filters: filter_params.clone(),
                     cur_it: 0,
@@ -77,6 +82,79 @@ impl LandingPages {
         let endpoint = format!("landing-pages/{}", page_id);
         let mut payload = HashMap::new();
         payload.insert("page_id".to_string(), page_id.to_string());
-        self.api.get::<LandingPage>(&endpoint, payload)
+        let mut page = self.api.get::<LandingPage>(&endpoint, payload)?;
+        page.set_api(self.api.clone());
+        page.set_endpoint("landing-pages");
+        Ok(page)
+    }
+
+    ///
+    /// Create a new landing page
+    ///
+    /// Arguments:
+    ///     param: The page to create, with its list and template,
+    ///         see `LandingPageParam::new`
+    ///
+    pub fn create(&self, param: LandingPageParam) -> MailchimpResult<LandingPage> {
+        // POST /landing-pages
+        param.validate_create()?;
+        let endpoint = "landing-pages";
+        let mut page = self
+            .api
+            .post::<LandingPage, LandingPageParam>(endpoint, param)?;
+        page.set_api(self.api.clone());
+        page.set_endpoint(endpoint);
+        Ok(page)
+    }
+
+    ///
+    /// Get the reports of the landing pages
+    ///
+    pub fn get_reports(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<LandingPageReportBuilder> {
+        // GET /reporting/landing-pages
+        let endpoint = "reporting/landing-pages";
+        let filter_params = filter.unwrap_or_default();
+
+        match self
+            .api
+            .get::<CollectionLandingPageReport>(endpoint, filter_params.build_payload())
+        {
+            Ok(collection) => MalchimpIter {
+                builder: LandingPageReportBuilder {},
+                data: collection.landing_pages,
+                cur_filters: filter_params.clone(),
+                cur_it: 0,
+                total_items: collection.total_items,
+                api: self.api.clone(),
+                endpoint: endpoint.to_string(),
+            },
+            Err(e) => {
+                error!( target: "mailchimp",  "Get Landing Page Reports: Response Error details: {:?}", e);
+                MalchimpIter {
+                    builder: LandingPageReportBuilder {},
+                    data: Vec::new(),
+                    cur_filters: filter_params.clone(),
+                    cur_it: 0,
+                    total_items: 0,
+                    api: self.api.clone(),
+                    endpoint: endpoint.to_string(),
+                }
+            }
+        }
+    }
+
+    ///
+    /// Get the report of a specific landing page
+    ///
+    /// Arguments:
+    ///     outreach_id: The outreach id of the landing page
+    ///
+    pub fn get_report(&self, outreach_id: &str) -> MailchimpResult<LandingPageReport> {
+        // GET /reporting/landing-pages/{outreach_id}
+        let endpoint = format!("reporting/landing-pages/{}", outreach_id);
+        self.api.get::<LandingPageReport>(&endpoint, HashMap::new())
     }
 }
@@ -26,9 +26,13 @@
 //!
 
 use super::api::{MailchimpApi, MailchimpApiUpdate};
+use super::internal::error_type::MailchimpErrorType;
 use super::internal::request::MailchimpResult;
-use super::iter::{BuildIter, MalchimpIter, ResourceFilter};
-use super::types::{ListParam, ListType, ListsType};
+use super::iter::{fetch_all, BuildIter, MalchimpIter, ResourceFilter};
+use super::types::{
+    DataSubjectExport, ErasureReport, FieldSelector, ListMember, ListParam, ListType, ListsType,
+    MemberDataExport, SubscriberHash,
+};
 use log::error;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -93,6 +97,32 @@ impl Default for ListFilter {
     }
 }
 
+impl ListFilter {
+    ///
+    /// Return only the selected fields of each resource in the collection
+    ///
+    pub fn select(mut self, fields: &FieldSelector<ListType>) -> Self {
+        self.fields = if fields.is_empty() {
+            None
+        } else {
+            Some(fields.build_for_collection())
+        };
+        self
+    }
+
+    ///
+    /// Exclude the selected fields from each resource in the collection
+    ///
+    pub fn exclude(mut self, fields: &FieldSelector<ListType>) -> Self {
+        self.exclude_fields = if fields.is_empty() {
+            None
+        } else {
+            Some(fields.build_exclude_for_collection())
+        };
+        self
+    }
+}
+
 impl ResourceFilter for ListFilter {
     fn build_payload(&self) -> HashMap<String, String> {
         let mut payload = HashMap::new();
@@ -324,4 +354,134 @@ impl Lists {
             endpoint: "lists".to_string(),
         }
     }
+
+    ///
+    /// Memberships of an email address in all the lists of the account. Only
+    /// the lists that include the email are requested; the lists where the
+    /// member can not be read are returned with the error. Fails when the
+    /// lists of the account can not be read.
+    ///
+    fn memberships(
+        &self,
+        email_address: &str,
+    ) -> MailchimpResult<Vec<(ListType, MailchimpResult<ListMember>)>> {
+        let hash = SubscriberHash::from_email(email_address);
+        let filters = ListFilter {
+            email: Some(email_address.trim().to_string()),
+            ..ListFilter::default()
+        };
+        let lists = fetch_all(&ListsBuilder {}, self.api.clone(), "lists", filters)?;
+        Ok(lists
+            .into_iter()
+            .map(|list| {
+                let member = list.get_member_info(&hash);
+                (list, member)
+            })
+            .filter(|(_, member)| match member {
+                Err(e) => e.status != 404,
+                Ok(_) => true,
+            })
+            .collect())
+    }
+
+    ///
+    /// Find an email address in all the lists of the account and return the
+    /// member of each list.
+    ///
+    /// Arguments:
+    ///     email_address: Email address of the person
+    ///
+    pub fn find_member(&self, email_address: &str) -> Vec<ListMember> {
+        let memberships = match self.memberships(email_address) {
+            Ok(memberships) => memberships,
+            Err(e) => {
+                error!( target: "mailchimp",  "Find member: Response Error details: {:?}", e);
+                return Vec::new();
+            }
+        };
+        memberships
+            .into_iter()
+            .filter_map(|(list, member)| match member {
+                Ok(m) => Some(m),
+                Err(e) => {
+                    error!( target: "mailchimp",  "Find member in list {:?}: Response Error details: {:?}", list.id, e);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    ///
+    /// Data subject access request: export everything stored about an email
+    /// address in all the lists of the account, the member data, notes, tags,
+    /// activity and events.
+    ///
+    /// A list goes to `failed` instead of `memberships` when any of its parts
+    /// can not be read, and an empty list id in `failed` means that the lists
+    /// of the account could not be read, so the export may miss lists.
+    ///
+    /// Arguments:
+    ///     email_address: Email address of the person
+    ///
+    pub fn export_member_data(&self, email_address: &str) -> DataSubjectExport {
+        let mut export = DataSubjectExport::new(email_address);
+        let memberships = match self.memberships(email_address) {
+            Ok(memberships) => memberships,
+            Err(e) => {
+                export.failed.push((String::new(), e));
+                return export;
+            }
+        };
+        for (list, member) in memberships {
+            let list_id = list.id.clone().unwrap_or_default();
+            let data = member.and_then(|member| {
+                Ok(MemberDataExport {
+                    list_id: list_id.clone(),
+                    list_name: list.name.clone().unwrap_or_default(),
+                    notes: member.fetch_all_notes()?,
+                    tags: member.fetch_all_tags()?,
+                    activity: member.fetch_all_activity_feed()?,
+                    events: member.fetch_all_events()?,
+                    member,
+                })
+            });
+            match data {
+                Ok(data) => export.memberships.push(data),
+                Err(e) => export.failed.push((list_id, e)),
+            }
+        }
+        export
+    }
+
+    ///
+    /// Data subject erasure request: export everything stored about an email
+    /// address and then permanently delete the member from every list. The
+    /// report keeps the digest of the export and the lists that failed, the
+    /// export itself is returned to hand it over or to archive it.
+    ///
+    /// The member is only deleted from the lists whose export is complete,
+    /// the lists that could not be exported stay in the report as failures.
+    /// Nothing is deleted when the digest of the export can not be computed.
+    /// Permanently deleted members can not be re-imported, use it only for
+    /// erasure requests.
+    ///
+    /// Arguments:
+    ///     email_address: Email address of the person
+    ///
+    pub fn erase_member(
+        &self,
+        email_address: &str,
+    ) -> MailchimpResult<(DataSubjectExport, ErasureReport)> {
+        let export = self.export_member_data(email_address);
+        let mut report = ErasureReport::from_export(&export).map_err(|e| {
+            MailchimpErrorType::invalid_request(&format!(
+                "the digest of the export can not be computed: {}",
+                e
+            ))
+        })?;
+        for data in &export.memberships {
+            report.add_result(&data.list_id, data.member.permanently_delete());
+        }
+        Ok((export, report))
+    }
 }
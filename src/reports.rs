@@ -1,7 +1,7 @@
 use super::api::MailchimpApi;
 use super::internal::request::MailchimpResult;
-use super::types::{CollectionReports, ReportType, ReportsBuilder, ReportsFilter};
-use crate::iter::{MalchimpIter, ResourceFilter};
+use super::types::{CollectionReports, ReportRow, ReportType, ReportsBuilder, ReportsFilter};
+use crate::iter::{fetch_all, MalchimpIter, ResourceFilter};
 use log::error;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -69,6 +69,34 @@ impl Reports {
         }
     }
 
+    ///
+    /// Reports of the campaigns sent in a date range, flattened to export them
+    /// with `write_reports_csv` or `write_reports_jsonl`. Fails when any page
+    /// of reports can not be read, so the export is never silently short.
+    ///
+    /// Arguments:
+    ///     since_send_time: Campaigns sent after this time, ISO 8601 format
+    ///     before_send_time: Campaigns sent before this time, ISO 8601 format
+    ///     deltas: Compute the deltas against the list and industry averages
+    ///
+    pub fn export_reports(
+        &self,
+        since_send_time: Option<&str>,
+        before_send_time: Option<&str>,
+        deltas: bool,
+    ) -> MailchimpResult<Vec<ReportRow>> {
+        let filter = ReportsFilter {
+            since_send_time: since_send_time.map(|t| t.to_string()),
+            before_send_time: before_send_time.map(|t| t.to_string()),
+            ..ReportsFilter::default()
+        };
+        let reports = fetch_all(&ReportsBuilder {}, self.api.clone(), "reports", filter)?;
+        Ok(reports
+            .iter()
+            .map(|report| ReportRow::from_report(report, deltas))
+            .collect())
+    }
+
     ///
     /// Get a specific campaign report
     ///
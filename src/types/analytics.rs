@@ -0,0 +1,395 @@
+//! Typed time series built from the raw statistics of the reports and the
+//! growth history of the lists
+//!
+//! The instants are `ScheduleTime` values in UTC, with the `chrono` feature
+//! enabled the points also have `*_utc` accessors that return them as
+//! `DateTime<Utc>`, e.g. `SeriesPoint::time_utc`. The hourly breakdown of a campaign report becomes a
+//! `CampaignTimeSeries`, the growth history of a list becomes `MonthlyGrowth`
+//! rows and the Timewarp stats become `TimewarpPoint` rows.
+//!
+//! ```
+//!     use mailchimp::types::{Bucket, ScheduleTime, SeriesPoint, TimeSeries};
+//!
+//!     let at = |t: &str| ScheduleTime::parse(t).unwrap();
+//!     let opens = TimeSeries::new(vec![
+//!         SeriesPoint::new(at("2020-06-01T10:00:00Z"), 40),
+//!         SeriesPoint::new(at("2020-06-01T11:00:00Z"), 10),
+//!         SeriesPoint::new(at("2020-06-03T09:00:00Z"), 5),
+//!     ]);
+//!     let daily = opens.resample(Bucket::Daily);
+//!     let values: Vec<u64> = daily.points.iter().map(|p| p.value).collect();
+//!     assert_eq!(values, vec![50, 0, 5]);
+//! ```
+//!
+
+use super::campaign_schedule::ScheduleTime;
+use super::list_growth_history::ListGrowthHistoryType;
+use super::report::{ReportType, TimeSerieType, TimewarpStatsType};
+use super::timestamp::civil_from_days;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+const HOUR: i64 = 60 * 60;
+const DAY: i64 = 24 * HOUR;
+
+///
+/// Parse the dates of the reports: ISO 8601 with a timezone, or in UTC
+/// `2015-10-21 15:41:36`, `2015-10-21` and `2015-10`
+///
+pub fn parse_instant(value: &str) -> Option<ScheduleTime> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if let Ok(t) = ScheduleTime::parse(value) {
+        return Some(t);
+    }
+    let utc = match value.len() {
+        19 => format!("{}Z", value),
+        10 => format!("{}T00:00:00Z", value),
+        7 => format!("{}-01T00:00:00Z", value),
+        _ => return None,
+    };
+    ScheduleTime::parse(&utc).ok()
+}
+
+///
+/// Size of the buckets of `TimeSeries::resample`, in UTC
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bucket {
+    /// One bucket per day
+    Daily,
+    /// One bucket per week, starting on Monday
+    Weekly,
+}
+
+impl Bucket {
+    /// Start of the bucket of the instant
+    fn start(&self, time: ScheduleTime) -> ScheduleTime {
+        let day = time.unix().div_euclid(DAY);
+        let day = match self {
+            Bucket::Daily => day,
+            // 1970-01-01 was a Thursday
+            Bucket::Weekly => day - (day + 3).rem_euclid(7),
+        };
+        ScheduleTime::from_unix(day * DAY)
+    }
+
+    /// Length of the bucket in seconds
+    fn seconds(&self) -> i64 {
+        match self {
+            Bucket::Daily => DAY,
+            Bucket::Weekly => 7 * DAY,
+        }
+    }
+}
+
+///
+/// Value of a time series at an instant
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeriesPoint {
+    /// The instant, the start of the period for aggregated values
+    pub time: ScheduleTime,
+    /// The value
+    pub value: u64,
+}
+
+impl SeriesPoint {
+    ///
+    /// New point
+    ///
+    pub fn new(time: ScheduleTime, value: u64) -> Self {
+        SeriesPoint { time, value }
+    }
+
+    ///
+    /// `time` as `DateTime<Utc>`
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn time_utc(&self) -> Option<DateTime<Utc>> {
+        self.time.to_utc()
+    }
+}
+
+///
+/// Series of values sorted by time
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeSeries {
+    /// The points, sorted by time
+    pub points: Vec<SeriesPoint>,
+}
+
+impl TimeSeries {
+    ///
+    /// Series of the points, sorted by time
+    ///
+    pub fn new(mut points: Vec<SeriesPoint>) -> Self {
+        points.sort_by_key(|p| p.time);
+        TimeSeries { points }
+    }
+
+    ///
+    /// Sum of the values
+    ///
+    pub fn total(&self) -> u64 {
+        self.points.iter().map(|p| p.value).sum()
+    }
+
+    ///
+    /// Running total of the values
+    ///
+    pub fn cumulative(&self) -> TimeSeries {
+        let mut total = 0;
+        TimeSeries {
+            points: self
+                .points
+                .iter()
+                .map(|p| {
+                    total += p.value;
+                    SeriesPoint::new(p.time, total)
+                })
+                .collect(),
+        }
+    }
+
+    ///
+    /// Time from the first point until the given fraction of the total is
+    /// reached. Each point counts until the next one, the last one as long as
+    /// the previous step (an hour for a single point).
+    ///
+    pub fn time_to_fraction(&self, fraction: f64) -> Option<Duration> {
+        let total = self.total();
+        if total == 0 {
+            return None;
+        }
+        let target = (fraction.clamp(0.0, 1.0) * total as f64).ceil() as u64;
+        let first = self.points.first()?.time.unix();
+        let cumulative = self.cumulative();
+        let index = cumulative
+            .points
+            .iter()
+            .position(|p| p.value >= target.max(1))?;
+        let end = match self.points.get(index + 1) {
+            Some(next) => next.time.unix(),
+            None if index > 0 => {
+                let step = self.points[index].time.unix() - self.points[index - 1].time.unix();
+                self.points[index].time.unix() + step
+            }
+            None => self.points[index].time.unix() + HOUR,
+        };
+        Some(Duration::from_secs((end - first).max(0) as u64))
+    }
+
+    ///
+    /// Sum the values by day or by week. The empty buckets between the first
+    /// and the last point are kept with a zero value.
+    ///
+    pub fn resample(&self, bucket: Bucket) -> TimeSeries {
+        let (first, last) = match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (bucket.start(first.time), bucket.start(last.time)),
+            _ => return TimeSeries::default(),
+        };
+        let count = ((last.unix() - first.unix()) / bucket.seconds() + 1) as usize;
+        let mut points: Vec<SeriesPoint> = (0..count)
+            .map(|i| {
+                SeriesPoint::new(
+                    ScheduleTime::from_unix(first.unix() + i as i64 * bucket.seconds()),
+                    0,
+                )
+            })
+            .collect();
+        for p in &self.points {
+            let i = (bucket.start(p.time).unix() - first.unix()) / bucket.seconds();
+            points[i as usize].value += p.value;
+        }
+        TimeSeries { points }
+    }
+}
+
+///
+/// Hourly breakdown of a campaign over its first 24 hours
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CampaignTimeSeries {
+    /// The number of emails sent by hour.
+    pub emails_sent: TimeSeries,
+    /// The number of unique opens by hour.
+    pub unique_opens: TimeSeries,
+    /// The number of clicks by hour.
+    pub recipients_clicks: TimeSeries,
+}
+
+impl CampaignTimeSeries {
+    ///
+    /// Series of the `timeseries` of the report
+    ///
+    pub fn from_report(report: &ReportType) -> Self {
+        CampaignTimeSeries::from_points(&report.timeseries)
+    }
+
+    ///
+    /// Series of the raw points, the points without a valid date are skipped
+    ///
+    pub fn from_points(points: &[TimeSerieType]) -> Self {
+        let series = |value: fn(&TimeSerieType) -> u64| {
+            TimeSeries::new(
+                points
+                    .iter()
+                    .filter_map(|p| {
+                        parse_instant(&p.timestamp).map(|t| SeriesPoint::new(t, value(p)))
+                    })
+                    .collect(),
+            )
+        };
+        CampaignTimeSeries {
+            emails_sent: series(|p| p.emails_sent),
+            unique_opens: series(|p| p.unique_opens),
+            recipients_clicks: series(|p| p.recipients_clicks),
+        }
+    }
+
+    ///
+    /// Running total of the unique opens
+    ///
+    pub fn cumulative_opens(&self) -> TimeSeries {
+        self.unique_opens.cumulative()
+    }
+
+    ///
+    /// Running total of the clicks
+    ///
+    pub fn cumulative_clicks(&self) -> TimeSeries {
+        self.recipients_clicks.cumulative()
+    }
+
+    ///
+    /// Time from the first hour until half of the unique opens, rounded up to
+    /// the end of the hour
+    ///
+    pub fn time_to_half_opens(&self) -> Option<Duration> {
+        self.unique_opens.time_to_fraction(0.5)
+    }
+}
+
+///
+/// Growth of a list in a month, see `list_growth`
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MonthlyGrowth {
+    /// The year.
+    pub year: i64,
+    /// The month, 1 to 12.
+    pub month: i64,
+    /// Subscribed members at the end of the month.
+    pub subscribed: u64,
+    /// Members added in the month, the change of the subscribed members plus
+    /// the members lost. `None` for the first month.
+    pub added: Option<u64>,
+    /// Members lost in the month, unsubscribed or cleaned.
+    pub lost: u64,
+    /// Change of the subscribed members against the previous month, 0.05 is 5%.
+    pub growth_rate: Option<f64>,
+    /// Members lost divided by the subscribed members of the previous month.
+    pub churn_rate: Option<f64>,
+}
+
+///
+/// Month-over-month growth and churn of a list from its growth history, sorted
+/// by month. It is based on the subscribed, unsubscribed and cleaned members,
+/// `existing`, `imports` and `optins` are deprecated by Mailchimp. The rates of
+/// the first month and of the months after an empty list are `None`.
+///
+pub fn list_growth(history: &[ListGrowthHistoryType]) -> Vec<MonthlyGrowth> {
+    let mut months: Vec<(i64, &ListGrowthHistoryType)> = history
+        .iter()
+        .filter_map(|h| parse_instant(&h.month).map(|t| (t.unix(), h)))
+        .collect();
+    months.sort_by_key(|(t, _)| *t);
+
+    let mut previous: Option<u64> = None;
+    months
+        .into_iter()
+        .map(|(t, h)| {
+            let (year, month, _) = civil_from_days(t.div_euclid(DAY));
+            let lost = h.unsubscribed + h.cleaned;
+            let added = previous.map(|p| (h.subscribed + lost).saturating_sub(p));
+            let base = previous.filter(|p| *p > 0).map(|p| p as f64);
+            previous = Some(h.subscribed);
+            MonthlyGrowth {
+                year,
+                month,
+                subscribed: h.subscribed,
+                added,
+                lost,
+                growth_rate: base.map(|b| (h.subscribed as f64 - b) / b),
+                churn_rate: base.map(|b| lost as f64 / b),
+            }
+        })
+        .collect()
+}
+
+///
+/// Statistics of a Timewarp time zone group
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimewarpPoint {
+    /// The offset of the time zone group, in hours.
+    pub gmt_offset: i64,
+    /// The number of opens.
+    pub opens: u64,
+    /// The number of unique opens.
+    pub unique_opens: u64,
+    /// The number of clicks.
+    pub clicks: u64,
+    /// The number of unique clicks.
+    pub unique_clicks: u64,
+    /// The number of bounces.
+    pub bounces: u64,
+    /// The date and time of the last open.
+    pub last_open: Option<ScheduleTime>,
+    /// The date and time of the last click.
+    pub last_click: Option<ScheduleTime>,
+}
+
+impl TimewarpPoint {
+    ///
+    /// `last_open` as `DateTime<Utc>`
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn last_open_utc(&self) -> Option<DateTime<Utc>> {
+        self.last_open.and_then(|t| t.to_utc())
+    }
+
+    ///
+    /// `last_click` as `DateTime<Utc>`
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn last_click_utc(&self) -> Option<DateTime<Utc>> {
+        self.last_click.and_then(|t| t.to_utc())
+    }
+}
+
+///
+/// Timewarp stats of a report by time zone group, sorted by offset
+///
+pub fn timewarp_series(stats: &[TimewarpStatsType]) -> Vec<TimewarpPoint> {
+    let mut points: Vec<TimewarpPoint> = stats
+        .iter()
+        .map(|s| TimewarpPoint {
+            gmt_offset: s.gmt_offset,
+            opens: s.opens,
+            unique_opens: s.unique_opens,
+            clicks: s.clicks,
+            unique_clicks: s.unique_clicks,
+            bounces: s.bounces,
+            last_open: parse_instant(&s.last_open),
+            last_click: parse_instant(&s.last_click),
+        })
+        .collect();
+    points.sort_by_key(|p| p.gmt_offset);
+    points
+}
@@ -7,14 +7,21 @@ use super::ecommerce::ECommerceReportType;
 use super::empty::EmptyType;
 use super::link::LinkType;
 use super::list_segment_options::SegmentOptionsType;
+use super::status::AutomationStatus;
+use super::subscriber_hash::SubscriberHash;
+#[cfg(feature = "chrono")]
+use super::timestamp::parse_timestamp;
 use super::workflow_email::{WorkflowEmailType, WorkflowEmailsType};
 use crate::api::{MailchimpApi, MailchimpApiUpdate};
 use crate::internal::error_type::MailchimpErrorType;
 use crate::internal::request::MailchimpResult;
 use crate::iter::MailchimpCollection;
 use crate::iter::{MalchimpIter, ResourceFilter, SimpleFilter};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::TryInto;
 use std::rc::Rc;
 
 ///
@@ -445,7 +452,7 @@ pub struct AutomationWorkflowType {
 
     /// Desc: The current status of the Automation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AutomationStatus>,
 
     /// Desc: The total number of emails sent for the Automation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -493,7 +500,7 @@ impl MailchimpApiUpdate for AutomationWorkflowType {
 /// Update Params For Workflow Email
 ///
 #[derive(Serialize, Deserialize, Debug, Clone)]
-struct UpdateParamsForWorkflowEmail {
+pub(crate) struct UpdateParamsForWorkflowEmail {
     /// Settings for the campaign including the email subject, from name, and from email address.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<AutomationCampaignSettingsType>,
@@ -503,6 +510,22 @@ struct UpdateParamsForWorkflowEmail {
 }
 
 impl AutomationWorkflowType {
+    ///
+    /// `create_time` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn create_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.create_time.as_deref().and_then(parse_timestamp)
+    }
+
+    ///
+    /// `start_time` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn start_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.start_time.as_deref().and_then(parse_timestamp)
+    }
+
     // ============== Actions ==============
     ///
     /// Detiene todos los emails para esta automatización
@@ -539,6 +562,27 @@ impl AutomationWorkflowType {
             Err(e) => Some(e),
         }
     }
+
+    ///
+    /// Archive the Automation. Archiving will permanently end the automation and
+    /// keep the report data. You’ll be able to replicate your archived automation,
+    /// but you can’t restart it.
+    ///
+    /// En caso de ser satisfactoria la ejecución, devuelve None,
+    /// en caso contrario devuelve el error, con su respectiva descripción
+    ///
+    pub fn archive(&self) -> Option<MailchimpErrorType> {
+        // POST /automations/{workflow_id}/actions/archive
+        let b_endpoint = self.get_base_endpoint() + "/actions/archive";
+        match self
+            ._api
+            .post::<EmptyType, HashMap<String, String>>(b_endpoint.as_str(), HashMap::new())
+        {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
     ///
     /// Actualiza la automatización y devuelve una instancia nueva
     ///
@@ -585,7 +629,7 @@ impl AutomationWorkflowType {
                     .emails
                     .iter()
                     .map(move |data| {
-                        let mut inner = endpoint.clone();
+                        let mut inner = endpoint.clone() + "/";
                         inner.push_str(data.id.as_ref().unwrap());
                         let mut inner_data = data.clone();
                         inner_data.set_api(self._api.clone());
@@ -726,6 +770,32 @@ impl AutomationWorkflowType {
         }
     }
 
+    ///
+    /// Get information about a specific subscriber who was removed from the
+    /// Automation workflow.
+    ///
+    /// Arguments:
+    ///     subscriber: The email address or the MD5 hash of the lowercase
+    ///         version of the list member’s email address, see `SubscriberHash`.
+    ///
+    pub fn get_subscriber_removed<H>(
+        &self,
+        subscriber: H,
+    ) -> MailchimpResult<AutomationSubscriberType>
+    where
+        H: TryInto<SubscriberHash>,
+        MailchimpErrorType: From<H::Error>,
+    {
+        // GET /automations/{workflow_id}/removed-subscribers/{subscriber_hash}
+        let endpoint = format!(
+            "{}/removed-subscribers/{}",
+            self.get_base_endpoint(),
+            subscriber.try_into()?
+        );
+        self._api
+            .get::<AutomationSubscriberType>(&endpoint, HashMap::new())
+    }
+
     ///
     /// Remove a subscriber from a specific Automation workflow. You can remove a
     /// subscriber at any point in an Automation workflow, regardless of how many
@@ -735,17 +805,36 @@ impl AutomationWorkflowType {
     /// Arguments:
     ///     email_address: The list member’s email address.
     ///
-    pub fn add_subscriber_to_workflow<'a>(
+    pub fn remove_subscriber_from_workflow(
         &self,
-        email_address: &'a str,
+        email_address: &str,
     ) -> MailchimpResult<AutomationSubscriberType> {
         // POST /automations/{workflow_id}/removed-subscribers
-        let mut queue_endpoint = self.get_base_endpoint() + "/removed-subscribers";
-        queue_endpoint.push_str("/queue");
+        let endpoint = self.get_base_endpoint() + "/removed-subscribers";
         let mut payload = HashMap::new();
         payload.insert("email_address".to_string(), email_address.to_string());
         self._api
-            .post::<AutomationSubscriberType, HashMap<String, String>>(&queue_endpoint, payload)
+            .post::<AutomationSubscriberType, HashMap<String, String>>(&endpoint, payload)
+    }
+
+    ///
+    /// Remove a subscriber from the workflow, same as
+    /// `remove_subscriber_from_workflow`. It used to POST to
+    /// `/automations/{workflow_id}/removed-subscribers/queue`, which doesn't
+    /// exist; to add a subscriber to the queue of an email use
+    /// `WorkflowEmailType::add_subscriber_to_workflow`.
+    ///
+    /// Arguments:
+    ///     email_address: The list member’s email address.
+    ///
+    #[deprecated(note = "it removes the subscriber from the workflow, use \
+                `remove_subscriber_from_workflow` instead, or \
+                `WorkflowEmailType::add_subscriber_to_workflow` to add one")]
+    pub fn add_subscriber_to_workflow(
+        &self,
+        email_address: &str,
+    ) -> MailchimpResult<AutomationSubscriberType> {
+        self.remove_subscriber_from_workflow(email_address)
     }
 
     // ============== Private Functions ==============
@@ -5,17 +5,27 @@ use super::automation_campaign::{
     CampaignReportSummaryType, CampaignSettingsType, CampaignTrackingOptionsType, RecipientType,
     SocialCardType,
 };
-use super::campaign_content::{CampaignContentParam, CampaignContentType};
+use super::campaign_content::{CampaignContent, CampaignContentParam, CampaignContentType};
 use super::campaign_feedback::{
     CampaignFeedbackBuilder, CampaignFeedbackType, CollectionCampaignFeedback,
 };
+use super::campaign_schedule::CampaignSchedule;
 use super::campaign_send_checklist::SendChecklistType;
+use super::campaign_variate::VariateReport;
 use super::empty::EmptyType;
+use super::fields::{FieldProjection, FieldSelector};
 use super::link::LinkType;
+use super::report::ReportType;
+use super::status::{string_enum, CampaignKind, CampaignStatus, WinnerCriteria};
+#[cfg(feature = "chrono")]
+use super::timestamp::parse_timestamp;
 use crate::api::{MailchimpApi, MailchimpApiUpdate};
+use crate::internal::error_type::MailchimpErrorType;
 use crate::internal::request::MailchimpResult;
 use crate::iter::MailchimpCollection;
 use crate::iter::{MalchimpIter, ResourceFilter, SimpleFilter};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -203,6 +213,20 @@ impl Default for SendingScheduleType {
     }
 }
 
+string_enum! {
+    ///
+    /// How often an RSS Campaign is sent
+    ///
+    pub enum RssFrequency {
+        /// Every day
+        Daily => "daily",
+        /// Every week
+        Weekly => "weekly",
+        /// Every month
+        Monthly => "monthly",
+    }
+}
+
 ///
 /// RSS options for a campaign.
 ///
@@ -213,7 +237,7 @@ pub struct RSSOptionsType {
     pub feed_url: Option<String>,
     /// The frequency of the RSS Campaign.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<String>,
+    pub frequency: Option<RssFrequency>,
     /// The schedule for sending the RSS Campaign.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schedule: Option<SendingScheduleType>,
@@ -299,7 +323,7 @@ pub struct VariateSettingsType {
     ///  as the winner_criteria, the winner must be chosen in the Mailchimp
     /// web application.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub winner_criteria: Option<String>,
+    pub winner_criteria: Option<WinnerCriteria>,
     /// The number of minutes to wait before choosing the winning campaign.
     /// The value of wait_time must be greater than 0 and in whole hours,
     /// specified in minutes.
@@ -375,7 +399,7 @@ pub struct CampaignType {
     /// There are four types of campaigns you can create in Mailchimp. A/B Split
     /// campaigns have been deprecated and variate campaigns should be used instead.
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub campaign_type: Option<String>,
+    pub campaign_type: Option<CampaignKind>,
     /// The date and time the campaign was created in ISO 8601 format.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub create_time: Option<String>,
@@ -387,7 +411,7 @@ pub struct CampaignType {
     pub long_archive_url: Option<String>,
     /// The current status of the campaign.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CampaignStatus>,
     /// The total number of emails sent for this campaign.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub emails_sent: Option<u64>,
@@ -496,7 +520,7 @@ impl Default for CampaignsType {
 ///
 /// Schedule Batch Delivery
 ///
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct ScheduleBatchDelivery {
     /// The delay, in minutes, between batches.
     #[serde(default)]
@@ -533,7 +557,7 @@ pub struct ScheduleParam {
     pub timewarp: bool,
     /// Choose whether the campaign should use Batch Delivery. Cannot be set
     /// to true for campaigns using Timewarp.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub batch_delivery: Option<ScheduleBatchDelivery>,
 }
 
@@ -589,7 +613,108 @@ pub struct UpdateCampaignParam {
     pub social_card: Option<SocialCardType>,
 }
 
+impl FieldProjection for CampaignType {
+    fn collection_key() -> &'static str {
+        "campaigns"
+    }
+}
+
+impl FieldSelector<CampaignType> {
+    /// A string that uniquely identifies this campaign.
+    pub fn id(self) -> Self {
+        self.with("id")
+    }
+    /// The ID used in the Mailchimp web application.
+    pub fn web_id(self) -> Self {
+        self.with("web_id")
+    }
+    /// The campaign type.
+    pub fn campaign_type(self) -> Self {
+        self.with("type")
+    }
+    /// The date and time the campaign was created.
+    pub fn create_time(self) -> Self {
+        self.with("create_time")
+    }
+    /// The current status of the campaign.
+    pub fn status(self) -> Self {
+        self.with("status")
+    }
+    /// The total number of emails sent for this campaign.
+    pub fn emails_sent(self) -> Self {
+        self.with("emails_sent")
+    }
+    /// The date and time a campaign was sent.
+    pub fn send_time(self) -> Self {
+        self.with("send_time")
+    }
+    /// How the campaign’s content is put together.
+    pub fn content_type(self) -> Self {
+        self.with("content_type")
+    }
+    /// List settings for the campaign.
+    pub fn recipients(self) -> Self {
+        self.with("recipients")
+    }
+    /// The unique list id.
+    pub fn recipients_list_id(self) -> Self {
+        self.with("recipients.list_id")
+    }
+    /// The settings for your campaign.
+    pub fn settings(self) -> Self {
+        self.with("settings")
+    }
+    /// The title of the campaign.
+    pub fn settings_title(self) -> Self {
+        self.with("settings.title")
+    }
+    /// The subject line for the campaign.
+    pub fn settings_subject_line(self) -> Self {
+        self.with("settings.subject_line")
+    }
+    /// The settings specific to A/B test campaigns.
+    pub fn variate_settings(self) -> Self {
+        self.with("variate_settings")
+    }
+    /// The tracking options for a campaign.
+    pub fn tracking(self) -> Self {
+        self.with("tracking")
+    }
+    /// A summary of opens, clicks, and e-commerce data.
+    pub fn report_summary(self) -> Self {
+        self.with("report_summary")
+    }
+    /// Updates on campaigns in the process of sending.
+    pub fn delivery_status(self) -> Self {
+        self.with("delivery_status")
+    }
+}
+
 impl CampaignType {
+    ///
+    /// `create_time` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn create_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.create_time.as_deref().and_then(parse_timestamp)
+    }
+
+    ///
+    /// `send_time` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn send_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.send_time.as_deref().and_then(parse_timestamp)
+    }
+
+    ///
+    /// Typed selection of fields for the campaigns endpoints. The resource already
+    /// uses optional fields, so it can be used as the partial response type.
+    ///
+    pub fn fields() -> FieldSelector<CampaignType> {
+        FieldSelector::new()
+    }
+
     // ==== Actions ===========
     ///
     ///  Cancel a campaign
@@ -646,6 +771,42 @@ impl CampaignType {
         self._api.post::<EmptyType, ScheduleParam>(&endpoint, param)
     }
 
+    ///
+    /// Schedule a campaign for delivery, checking locally the quarter-hour rule,
+    /// that Timewarp is not used with Batch Delivery and the 24 hours of lead time
+    /// of Timewarp, see `CampaignSchedule`.
+    ///
+    pub fn schedule(&self, schedule: &CampaignSchedule) -> MailchimpResult<EmptyType> {
+        let param = schedule.build()?;
+        self.schedule_campaign(param)
+    }
+
+    ///
+    /// Report of this Multivariate Campaign by combination, with the winner
+    /// identified, see `VariateReport`. The variate settings are read again,
+    /// the winner may have been picked after this campaign was fetched.
+    ///
+    pub fn variate_report(&self) -> MailchimpResult<VariateReport> {
+        // GET /campaigns/{campaign_id}?fields=variate_settings
+        let mut payload = HashMap::new();
+        payload.insert("fields".to_string(), "variate_settings".to_string());
+        let campaign = self
+            ._api
+            .get::<CampaignType>(&self.get_base_endpoint(), payload)?;
+        let settings = match campaign.variate_settings {
+            Some(settings) => settings,
+            None => {
+                return Err(MailchimpErrorType::invalid_request(
+                    "the campaign has no variate settings",
+                ))
+            }
+        };
+        // GET /reports/{campaign_id}
+        let endpoint = String::from("reports/") + self.id.as_ref().unwrap();
+        let report = self._api.get::<ReportType>(&endpoint, HashMap::new())?;
+        Ok(VariateReport::new(&settings, &report))
+    }
+
     ///
     /// Send a Mailchimp campaign. For RSS Campaigns, the campaign will send
     /// according to its schedule. All other campaigns will send immediately.
@@ -742,6 +903,14 @@ impl CampaignType {
             .put::<CampaignContentType, CampaignContentParam>(&endpoint, param)
     }
 
+    ///
+    /// Set the content for a campaign, sending only the chosen mode, see
+    /// `CampaignContent`.
+    ///
+    pub fn set_content(&self, content: CampaignContent) -> MailchimpResult<CampaignContentType> {
+        self.update_content(content.into())
+    }
+
     // ======================== Send Checklist ===========
     ///
     /// Review the send checklist for a campaign, and resolve any issues before sending.
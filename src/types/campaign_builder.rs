@@ -0,0 +1,483 @@
+//! Builder of the params to create a campaign, see `Campaigns::create`
+//!
+//! The required fields depend on the kind of campaign, they are checked by
+//! the builder before sending the request.
+//!
+//! ```
+//!     use mailchimp::types::CampaignBuilder;
+//!
+//!     let param = CampaignBuilder::regular()
+//!         .list("57afe96172")
+//!         .subject_line("Your weekly jokes")
+//!         .from_name("Freddie")
+//!         .reply_to("freddie@freddiesjokes.com")
+//!         .build()
+//!         .unwrap();
+//!     assert_eq!(param.campaign_type.as_str(), "regular");
+//!
+//!     // The subject line is missing
+//!     assert!(CampaignBuilder::plaintext().list("57afe96172").build().is_err());
+//! ```
+//!
+
+use super::automation_campaign::{
+    CampaignSettingsType, CampaignTrackingOptionsType, RecipientType, SocialCardType,
+};
+use super::campaign::{RSSOptionsType, RssFrequency, SendingScheduleType, VariateSettingsType};
+use super::campaign_variate::{check_variate_settings, VariateError};
+use super::list_segment_options::SegmentOptionsType;
+use super::status::CampaignKind;
+use crate::internal::error_type::MailchimpErrorType;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+///
+/// Params to create a campaign
+///
+/// Endpoint
+///     POST /campaigns
+///
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CampaignParam {
+    /// The type of campaign.
+    #[serde(rename = "type")]
+    pub campaign_type: CampaignKind,
+    /// List settings for the campaign.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipients: Option<RecipientType>,
+    /// The settings for your campaign, including subject, from name,
+    /// reply-to address, and more.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<CampaignSettingsType>,
+    /// The settings specific to A/B test campaigns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variate_settings: Option<VariateSettingsType>,
+    /// The tracking options for a campaign.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking: Option<CampaignTrackingOptionsType>,
+    /// RSS options for a campaign.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rss_opts: Option<RSSOptionsType>,
+    /// The preview for the campaign, rendered by social networks like
+    /// Facebook and Twitter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub social_card: Option<SocialCardType>,
+}
+
+///
+/// Campaign Builder
+///
+/// Assemble the params of a new campaign of a given kind
+///
+#[derive(Debug, Clone)]
+pub struct CampaignBuilder {
+    kind: CampaignKind,
+    recipients: RecipientType,
+    settings: CampaignSettingsType,
+    tracking: Option<CampaignTrackingOptionsType>,
+    rss_opts: Option<RSSOptionsType>,
+    variate_settings: Option<VariateSettingsType>,
+    social_card: Option<SocialCardType>,
+}
+
+impl CampaignBuilder {
+    ///
+    /// New builder for a campaign of the given kind
+    ///
+    pub fn new(kind: CampaignKind) -> Self {
+        CampaignBuilder {
+            kind,
+            recipients: RecipientType::default(),
+            settings: CampaignSettingsType::default(),
+            tracking: None,
+            rss_opts: None,
+            variate_settings: None,
+            social_card: None,
+        }
+    }
+
+    ///
+    /// Regular campaign, HTML and plain-text content
+    ///
+    pub fn regular() -> Self {
+        CampaignBuilder::new(CampaignKind::Regular)
+    }
+
+    ///
+    /// Plain-text campaign
+    ///
+    pub fn plaintext() -> Self {
+        CampaignBuilder::new(CampaignKind::Plaintext)
+    }
+
+    ///
+    /// RSS-driven campaign
+    ///
+    /// Arguments:
+    ///     feed_url: The URL for the RSS feed.
+    ///     frequency: The frequency of the RSS Campaign.
+    ///
+    pub fn rss(feed_url: &str, frequency: RssFrequency) -> Self {
+        CampaignBuilder::new(CampaignKind::Rss).rss_options(RSSOptionsType {
+            feed_url: Some(feed_url.to_string()),
+            frequency: Some(frequency),
+            ..RSSOptionsType::default()
+        })
+    }
+
+    ///
+    /// Multivariate campaign
+    ///
+    /// Arguments:
+    ///     variate_settings: The combinations to test and how to choose the winner, see `VariateConfig`.
+    ///
+    pub fn variate(variate_settings: VariateSettingsType) -> Self {
+        CampaignBuilder::new(CampaignKind::Variate).variate_settings(variate_settings)
+    }
+
+    // ============== Recipients ==============
+
+    ///
+    /// The list of the campaign
+    ///
+    pub fn list(mut self, list_id: &str) -> Self {
+        self.recipients.list_id = Some(list_id.to_string());
+        self
+    }
+
+    ///
+    /// Send only to a segment of the list, see `Segment`
+    ///
+    pub fn segment<S: Into<SegmentOptionsType>>(mut self, segment: S) -> Self {
+        self.recipients.segment_opts = Some(segment.into());
+        self
+    }
+
+    // ============== Settings ==============
+
+    ///
+    /// Replace all the settings of the campaign
+    ///
+    pub fn settings(mut self, settings: CampaignSettingsType) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    ///
+    /// The subject line for the campaign.
+    ///
+    pub fn subject_line(mut self, subject_line: &str) -> Self {
+        self.settings.subject_line = Some(subject_line.to_string());
+        self
+    }
+
+    ///
+    /// The preview text for the campaign.
+    ///
+    pub fn preview_text(mut self, preview_text: &str) -> Self {
+        self.settings.preview_text = Some(preview_text.to_string());
+        self
+    }
+
+    ///
+    /// The title of the campaign.
+    ///
+    pub fn title(mut self, title: &str) -> Self {
+        self.settings.title = Some(title.to_string());
+        self
+    }
+
+    ///
+    /// The ‘from’ name on the campaign (not an email address).
+    ///
+    pub fn from_name(mut self, from_name: &str) -> Self {
+        self.settings.from_name = Some(from_name.to_string());
+        self
+    }
+
+    ///
+    /// The reply-to email address for the campaign.
+    ///
+    pub fn reply_to(mut self, reply_to: &str) -> Self {
+        self.settings.reply_to = Some(reply_to.to_string());
+        self
+    }
+
+    ///
+    /// The campaign’s custom ‘To’ name, typically the first name merge field.
+    ///
+    pub fn to_name(mut self, to_name: &str) -> Self {
+        self.settings.to_name = Some(to_name.to_string());
+        self
+    }
+
+    ///
+    /// The id of the folder of the campaign.
+    ///
+    pub fn folder(mut self, folder_id: &str) -> Self {
+        self.settings.folder_id = Some(folder_id.to_string());
+        self
+    }
+
+    ///
+    /// The id of the template to use.
+    ///
+    pub fn template(mut self, template_id: u64) -> Self {
+        self.settings.template_id = Some(template_id);
+        self
+    }
+
+    // ============== Options ==============
+
+    ///
+    /// The tracking options for the campaign.
+    ///
+    pub fn tracking(mut self, tracking: CampaignTrackingOptionsType) -> Self {
+        self.tracking = Some(tracking);
+        self
+    }
+
+    ///
+    /// RSS options for the campaign.
+    ///
+    pub fn rss_options(mut self, rss_opts: RSSOptionsType) -> Self {
+        self.rss_opts = Some(rss_opts);
+        self
+    }
+
+    ///
+    /// The schedule for sending the RSS campaign.
+    ///
+    pub fn rss_schedule(mut self, schedule: SendingScheduleType) -> Self {
+        let rss_opts = self.rss_opts.take().unwrap_or_default();
+        self.rss_opts = Some(RSSOptionsType {
+            schedule: Some(schedule),
+            ..rss_opts
+        });
+        self
+    }
+
+    ///
+    /// The settings specific to A/B test campaigns.
+    ///
+    pub fn variate_settings(mut self, variate_settings: VariateSettingsType) -> Self {
+        self.variate_settings = Some(variate_settings);
+        self
+    }
+
+    ///
+    /// The preview for the campaign, rendered by social networks.
+    ///
+    pub fn social_card(mut self, social_card: SocialCardType) -> Self {
+        self.social_card = Some(social_card);
+        self
+    }
+
+    // ============== Validation ==============
+
+    ///
+    /// Check the required fields for the kind of campaign, `Err` with the
+    /// reason when something is missing
+    ///
+    pub fn validate(&self) -> Result<(), CampaignBuildError> {
+        match self.kind {
+            CampaignKind::Regular
+            | CampaignKind::Plaintext
+            | CampaignKind::Rss
+            | CampaignKind::Variate => {}
+            _ => return Err(CampaignBuildError::UnsupportedKind(self.kind.clone())),
+        }
+        if is_blank(&self.recipients.list_id) {
+            return Err(CampaignBuildError::MissingList);
+        }
+        if self.kind == CampaignKind::Plaintext && self.settings.template_id.is_some() {
+            return Err(CampaignBuildError::PlaintextWithTemplate);
+        }
+        if self.kind == CampaignKind::Rss {
+            self.validate_rss()?;
+        }
+
+        let variate = if self.kind == CampaignKind::Variate {
+            Some(self.validate_variate()?)
+        } else {
+            None
+        };
+        let tested = |values: fn(&VariateSettingsType) -> &Option<Vec<String>>| {
+            variate
+                .map(|v| values(v).as_ref().map(|l| !l.is_empty()).unwrap_or(false))
+                .unwrap_or(false)
+        };
+        if is_blank(&self.settings.subject_line) && !tested(|v| &v.subject_lines) {
+            return Err(CampaignBuildError::MissingSubjectLine);
+        }
+        if is_blank(&self.settings.from_name) && !tested(|v| &v.from_names) {
+            return Err(CampaignBuildError::MissingFromName);
+        }
+        if !tested(|v| &v.reply_to_addresses) {
+            match &self.settings.reply_to {
+                Some(r) if r.contains('@') => {}
+                Some(r) if !r.trim().is_empty() => {
+                    return Err(CampaignBuildError::InvalidReplyTo(r.clone()))
+                }
+                _ => return Err(CampaignBuildError::MissingReplyTo),
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_rss(&self) -> Result<(), CampaignBuildError> {
+        let rss = match &self.rss_opts {
+            Some(rss) => rss,
+            None => return Err(CampaignBuildError::MissingRssOptions),
+        };
+        if is_blank(&rss.feed_url) {
+            return Err(CampaignBuildError::MissingRssFeedUrl);
+        }
+        let schedule = rss.schedule.clone().unwrap_or_default();
+        match rss.frequency {
+            Some(RssFrequency::Daily) => {}
+            Some(RssFrequency::Weekly) if schedule.weekly_send_day.is_none() => {
+                return Err(CampaignBuildError::MissingRssWeeklySendDay)
+            }
+            Some(RssFrequency::Monthly) if schedule.monthly_send_date.is_none() => {
+                return Err(CampaignBuildError::MissingRssMonthlySendDate)
+            }
+            Some(RssFrequency::Weekly) | Some(RssFrequency::Monthly) => {}
+            _ => return Err(CampaignBuildError::InvalidRssFrequency),
+        }
+        match schedule.hour {
+            Some(hour) if hour > 23 => Err(CampaignBuildError::InvalidRssHour(hour)),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_variate(&self) -> Result<&VariateSettingsType, CampaignBuildError> {
+        let variate = match &self.variate_settings {
+            Some(v) => v,
+            None => return Err(CampaignBuildError::MissingVariateSettings),
+        };
+        check_variate_settings(variate)?;
+        // The winner is picked by send time when the send times are tested
+        let tests_send_times = variate.send_times.as_ref().map(|t| t.len() > 1);
+        if variate.winner_criteria.is_none() && tests_send_times != Some(true) {
+            return Err(CampaignBuildError::MissingWinnerCriteria);
+        }
+        Ok(variate)
+    }
+
+    ///
+    /// Validate and return the params to create the campaign
+    ///
+    pub fn build(self) -> Result<CampaignParam, MailchimpErrorType> {
+        self.validate()?;
+        Ok(CampaignParam {
+            campaign_type: self.kind,
+            recipients: Some(self.recipients),
+            settings: Some(self.settings),
+            variate_settings: self.variate_settings,
+            tracking: self.tracking,
+            rss_opts: self.rss_opts,
+            social_card: self.social_card,
+        })
+    }
+}
+
+///
+/// Reasons why the params of a campaign are rejected
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CampaignBuildError {
+    /// Campaigns of this type can't be created through the API
+    UnsupportedKind(CampaignKind),
+    /// The list of the campaign is missing
+    MissingList,
+    /// A plain-text campaign has a template
+    PlaintextWithTemplate,
+    /// The subject line is missing and not tested
+    MissingSubjectLine,
+    /// The from name is missing and not tested
+    MissingFromName,
+    /// The reply-to address is missing and not tested
+    MissingReplyTo,
+    /// The reply-to address is not an email address
+    InvalidReplyTo(String),
+    /// An RSS campaign has no RSS options
+    MissingRssOptions,
+    /// The RSS feed url is missing
+    MissingRssFeedUrl,
+    /// A weekly RSS campaign has no weekly send day
+    MissingRssWeeklySendDay,
+    /// A monthly RSS campaign has no monthly send date
+    MissingRssMonthlySendDate,
+    /// The RSS frequency is not daily, weekly or monthly
+    InvalidRssFrequency,
+    /// The RSS send hour is not 0-23
+    InvalidRssHour(u64),
+    /// A variate campaign has no variate settings
+    MissingVariateSettings,
+    /// A variate campaign that doesn't test send times has no winner criteria
+    MissingWinnerCriteria,
+    /// The variate settings are rejected, see `VariateError`
+    Variate(VariateError),
+}
+
+impl fmt::Display for CampaignBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CampaignBuildError::UnsupportedKind(kind) => {
+                write!(f, "campaigns of type `{}` can't be created", kind.as_str())
+            }
+            CampaignBuildError::MissingList => write!(f, "the list of the campaign is required"),
+            CampaignBuildError::PlaintextWithTemplate => {
+                write!(f, "a plain-text campaign can't use a template")
+            }
+            CampaignBuildError::MissingSubjectLine => write!(f, "the subject line is required"),
+            CampaignBuildError::MissingFromName => write!(f, "the from name is required"),
+            CampaignBuildError::MissingReplyTo => write!(f, "the reply-to address is required"),
+            CampaignBuildError::InvalidReplyTo(r) => {
+                write!(f, "the reply-to `{}` is not an email address", r)
+            }
+            CampaignBuildError::MissingRssOptions => write!(f, "the RSS options are required"),
+            CampaignBuildError::MissingRssFeedUrl => write!(f, "the RSS feed url is required"),
+            CampaignBuildError::MissingRssWeeklySendDay => {
+                write!(f, "a weekly RSS campaign needs the weekly send day")
+            }
+            CampaignBuildError::MissingRssMonthlySendDate => {
+                write!(f, "a monthly RSS campaign needs the monthly send date")
+            }
+            CampaignBuildError::InvalidRssFrequency => {
+                write!(f, "the RSS frequency must be daily, weekly or monthly")
+            }
+            CampaignBuildError::InvalidRssHour(hour) => {
+                write!(f, "the RSS send hour must be 0-23, got {}", hour)
+            }
+            CampaignBuildError::MissingVariateSettings => {
+                write!(f, "the variate settings are required")
+            }
+            CampaignBuildError::MissingWinnerCriteria => write!(
+                f,
+                "the winner criteria is required unless the send times are tested"
+            ),
+            CampaignBuildError::Variate(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for CampaignBuildError {}
+
+impl From<VariateError> for CampaignBuildError {
+    fn from(value: VariateError) -> Self {
+        CampaignBuildError::Variate(value)
+    }
+}
+
+impl From<CampaignBuildError> for MailchimpErrorType {
+    fn from(value: CampaignBuildError) -> Self {
+        MailchimpErrorType::invalid_request(&value.to_string())
+    }
+}
+
+fn is_blank(value: &Option<String>) -> bool {
+    value.as_ref().map(|v| v.trim().is_empty()).unwrap_or(true)
+}
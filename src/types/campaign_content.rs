@@ -2,8 +2,14 @@
 //!
 
 use super::link::LinkType;
+use super::merge_tags::html_to_text;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+#[cfg(feature = "zip")]
+use std::path::PathBuf;
 
 ///
 ///  Use this template to generate the HTML content for the campaign.
@@ -12,7 +18,7 @@ use std::collections::HashMap;
 pub struct TemplateContent {
     /// The id of the template to use.
     #[serde(default)]
-    pub id: String,
+    pub id: u64,
     /// Content for the sections of the template. Each key should be
     /// the unique mc:edit area name from the template.
     #[serde(default)]
@@ -22,12 +28,21 @@ pub struct TemplateContent {
 impl Default for TemplateContent {
     fn default() -> Self {
         TemplateContent {
-            id: "".to_string(),
+            id: 0,
             sections: HashMap::new(),
         }
     }
 }
 
+impl TemplateContent {
+    ///
+    /// Return true when no template is set
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.id == 0 && self.sections.is_empty()
+    }
+}
+
 ///
 ///  Use this template to generate the HTML content for the campaign.
 ///
@@ -51,6 +66,101 @@ impl Default for UploadArchive {
     }
 }
 
+impl UploadArchive {
+    ///
+    /// Return true when no archive is set
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.archive_content.is_empty()
+    }
+
+    ///
+    /// Archive from its raw bytes, encoded as base64
+    ///
+    /// Arguments:
+    ///     bytes: Content of the archive file
+    ///     archive_type: zip, tar.gz, tar.bz2, tar, tgz or tbz
+    ///
+    pub fn from_bytes(bytes: &[u8], archive_type: &str) -> Self {
+        UploadArchive {
+            archive_content: base64::encode(bytes),
+            archive_type: archive_type.to_string(),
+        }
+    }
+
+    ///
+    /// Archive from a file, the type is taken from the extension of the file
+    ///
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        let archive_type = ARCHIVE_TYPES
+            .iter()
+            .find(|t| name.ends_with(&format!(".{}", t)))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "{:?} is not a zip, tar.gz, tar.bz2, tar, tgz or tbz file",
+                        path
+                    ),
+                )
+            })?;
+        Ok(UploadArchive::from_bytes(&fs::read(path)?, archive_type))
+    }
+
+    ///
+    /// Zip archive with all the files of a local directory, e.g. the HTML
+    /// and its images. Only with the `zip` feature.
+    ///
+    #[cfg(feature = "zip")]
+    pub fn from_dir<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        use std::io::Write;
+        use zip::write::FileOptions;
+        use zip::CompressionMethod;
+
+        let root = path.as_ref();
+        let mut files = Vec::new();
+        collect_files(root, &mut files)?;
+        files.sort();
+
+        let mut writer = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        for file in files {
+            let name = file
+                .strip_prefix(root)
+                .unwrap_or(&file)
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<String>>()
+                .join("/");
+            writer.start_file(name, options)?;
+            writer.write_all(&fs::read(&file)?)?;
+        }
+        let bytes = writer.finish()?.into_inner();
+        Ok(UploadArchive::from_bytes(&bytes, "zip"))
+    }
+}
+
+/// Archive types accepted by Mailchimp, longest extensions first
+const ARCHIVE_TYPES: [&str; 6] = ["tar.gz", "tar.bz2", "zip", "tar", "tgz", "tbz"];
+
+#[cfg(feature = "zip")]
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
 ///
 /// Variate Content
 ///
@@ -61,20 +171,20 @@ pub struct VariateContent {
     pub content_label: String,
     /// The plain-text portion of the campaign. If left unspecified,
     /// we’ll generate this automatically.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub plain_text: String,
     /// The raw HTML for the campaign.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub html: String,
     /// When importing a campaign, the URL for the HTML.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub url: String,
     /// Use this template to generate the HTML content for the campaign.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "TemplateContent::is_empty")]
     pub template: TemplateContent,
     /// Available when uploading an archive to create campaign content.
     /// The archive should include all campaign content and images. Learn more.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "UploadArchive::is_empty")]
     pub archive: UploadArchive,
 }
 
@@ -116,27 +226,158 @@ pub struct CampaignContentType {
 ///
 /// Campaign Content Param
 ///
-#[derive(Serialize, Deserialize, Debug, Clone)]
+///
+/// Only the values set are sent, see `CampaignContent` to build it.
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct CampaignContentParam {
     /// The plain-text portion of the campaign. If left unspecified, we’ll generate this automatically.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub plain_text: String,
     /// The raw HTML for the campaign.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub html: String,
     /// When importing a campaign, the URL where the HTML lives.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub url: String,
     /// Use this template to generate the HTML content of the campaign
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "TemplateContent::is_empty")]
     pub template: TemplateContent,
     /// Available when uploading an archive to create campaign content.
     /// The archive should include all campaign content and images. Learn more.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "UploadArchive::is_empty")]
     pub archive: UploadArchive,
     /// Content options for Multivariate Campaigns. Each content option must
     /// provide HTML content and may optionally provide plain text. For campaigns
     /// not testing content, only one object should be provided.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub variate_contents: Vec<VariateContent>,
 }
+
+///
+/// Content of a campaign, only one of the modes can be used at a time
+///
+/// ```
+///     use mailchimp::types::{CampaignContent, CampaignContentParam};
+///
+///     let param: CampaignContentParam = CampaignContent::html("<p>Hi *|FNAME|*</p>", None).into();
+///     assert_eq!(
+///         serde_json::to_string(&param).unwrap(),
+///         r#"{"html":"<p>Hi *|FNAME|*</p>"}"#
+///     );
+/// ```
+///
+#[derive(Debug, Clone)]
+pub enum CampaignContent {
+    /// Raw HTML and, optionally, its plain-text version
+    Html {
+        /// The raw HTML for the campaign.
+        html: String,
+        /// The plain-text portion of the campaign, generated when `None`.
+        plain_text: Option<String>,
+    },
+    /// Import the HTML from a URL
+    Url(String),
+    /// Generate the HTML from a template
+    Template(TemplateContent),
+    /// Upload an archive with the content and the images
+    Archive(UploadArchive),
+    /// Content options for Multivariate Campaigns
+    Variate(Vec<VariateContent>),
+}
+
+impl CampaignContent {
+    ///
+    /// Raw HTML, the plain-text is generated by Mailchimp when `None`
+    ///
+    pub fn html(html: &str, plain_text: Option<&str>) -> Self {
+        CampaignContent::Html {
+            html: html.to_string(),
+            plain_text: plain_text.map(|t| t.to_string()),
+        }
+    }
+
+    ///
+    /// Raw HTML with its plain-text version generated locally, see `html_to_text`
+    ///
+    pub fn html_with_text(html: &str) -> Self {
+        CampaignContent::Html {
+            html: html.to_string(),
+            plain_text: Some(html_to_text(html)),
+        }
+    }
+
+    ///
+    /// Import the HTML from a URL
+    ///
+    pub fn url(url: &str) -> Self {
+        CampaignContent::Url(url.to_string())
+    }
+
+    ///
+    /// Template with the content of its sections, the keys are the unique
+    /// mc:edit area names of the template
+    ///
+    pub fn template<S: Into<String>>(
+        template_id: u64,
+        sections: impl IntoIterator<Item = (S, S)>,
+    ) -> Self {
+        CampaignContent::Template(TemplateContent {
+            id: template_id,
+            sections: sections
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        })
+    }
+
+    ///
+    /// Archive file (zip, tar.gz, tar.bz2, tar, tgz or tbz)
+    ///
+    pub fn archive_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(CampaignContent::Archive(UploadArchive::from_file(path)?))
+    }
+
+    ///
+    /// Zip archive built from a local directory. Only with the `zip` feature.
+    ///
+    #[cfg(feature = "zip")]
+    pub fn archive_dir<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(CampaignContent::Archive(UploadArchive::from_dir(path)?))
+    }
+
+    ///
+    /// Content options for Multivariate Campaigns
+    ///
+    pub fn variate(contents: Vec<VariateContent>) -> Self {
+        CampaignContent::Variate(contents)
+    }
+}
+
+impl From<CampaignContent> for CampaignContentParam {
+    fn from(value: CampaignContent) -> Self {
+        match value {
+            CampaignContent::Html { html, plain_text } => CampaignContentParam {
+                html,
+                plain_text: plain_text.unwrap_or_default(),
+                ..CampaignContentParam::default()
+            },
+            CampaignContent::Url(url) => CampaignContentParam {
+                url,
+                ..CampaignContentParam::default()
+            },
+            CampaignContent::Template(template) => CampaignContentParam {
+                template,
+                ..CampaignContentParam::default()
+            },
+            CampaignContent::Archive(archive) => CampaignContentParam {
+                archive,
+                ..CampaignContentParam::default()
+            },
+            CampaignContent::Variate(variate_contents) => CampaignContentParam {
+                variate_contents,
+                ..CampaignContentParam::default()
+            },
+        }
+    }
+}
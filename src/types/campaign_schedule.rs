@@ -0,0 +1,372 @@
+//! Typed scheduling of campaigns, see `CampaignType::schedule`
+//!
+//! Mailchimp only accepts delivery times on the quarter-hour, doesn't allow
+//! Timewarp together with Batch Delivery and needs Timewarp campaigns to be
+//! scheduled at least 24 hours in advance. These rules are checked locally.
+//!
+//! ```
+//!     use mailchimp::types::{CampaignSchedule, QuarterHourRule, ScheduleTime};
+//!
+//!     let now = ScheduleTime::parse("2020-06-01T10:00:00Z").unwrap();
+//!     let at = ScheduleTime::parse("2020-06-01T14:07:00+02:00").unwrap();
+//!
+//!     // 14:07 isn't on the quarter-hour
+//!     assert!(CampaignSchedule::at(at).build_at(now).is_err());
+//!
+//!     let param = CampaignSchedule::at(at)
+//!         .rounding(QuarterHourRule::RoundUp)
+//!         .build_at(now)
+//!         .unwrap();
+//!     assert_eq!(param.schedule_time, "2020-06-01T12:15:00+00:00");
+//! ```
+//!
+
+use super::campaign::{ScheduleBatchDelivery, ScheduleParam};
+use super::timestamp::{civil_from_days, days_from_civil, days_in_month};
+use crate::internal::error_type::MailchimpErrorType;
+use std::error::Error;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const QUARTER_HOUR: i64 = 15 * 60;
+const TIMEWARP_LEAD_TIME: i64 = 24 * 60 * 60;
+
+///
+/// Instant of the delivery, kept as seconds since the Unix epoch in UTC
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScheduleTime(i64);
+
+impl ScheduleTime {
+    ///
+    /// Instant from the seconds since the Unix epoch
+    ///
+    pub fn from_unix(seconds: i64) -> Self {
+        ScheduleTime(seconds)
+    }
+
+    ///
+    /// The current instant
+    ///
+    pub fn now() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        ScheduleTime(seconds)
+    }
+
+    ///
+    /// Parse an ISO 8601 date and time with its timezone, e.g.
+    /// `2020-06-01T14:00:00+02:00` or `2020-06-01 12:00Z`. Dates without a
+    /// timezone are rejected, the moment they refer to is ambiguous.
+    ///
+    pub fn parse(value: &str) -> Result<Self, ScheduleError> {
+        let invalid = || ScheduleError::InvalidTime(value.to_string());
+        let value = value.trim();
+        let number = |from: usize, to: usize| -> Result<i64, ScheduleError> {
+            value
+                .get(from..to)
+                .filter(|v| v.chars().all(|c| c.is_ascii_digit()))
+                .and_then(|v| v.parse().ok())
+                .ok_or_else(invalid)
+        };
+        let separators = [(4, '-'), (7, '-'), (13, ':')];
+        if separators
+            .iter()
+            .any(|(i, c)| value.chars().nth(*i) != Some(*c))
+            || !matches!(value.chars().nth(10), Some('T') | Some('t') | Some(' '))
+        {
+            return Err(invalid());
+        }
+        let (year, month, day) = (number(0, 4)?, number(5, 7)?, number(8, 10)?);
+        let (hour, minute) = (number(11, 13)?, number(14, 16)?);
+        let mut rest = &value[16..];
+        let mut second = 0;
+        if rest.starts_with(':') {
+            second = number(17, 19)?;
+            rest = &value[19..];
+            if rest.starts_with('.') {
+                rest = rest.trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+            }
+        }
+        if !(1..=12).contains(&month)
+            || day < 1
+            || day > days_in_month(year, month)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return Err(invalid());
+        }
+        let offset = match rest {
+            "Z" | "z" => 0,
+            "" => return Err(ScheduleError::MissingTimezone(value.to_string())),
+            _ => {
+                let sign = match rest.chars().next() {
+                    Some('+') => 1,
+                    Some('-') => -1,
+                    _ => return Err(invalid()),
+                };
+                let digits: String = rest[1..].chars().filter(|c| *c != ':').collect();
+                if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                let hours: i64 = digits[0..2].parse().map_err(|_| invalid())?;
+                let minutes: i64 = digits[2..4].parse().map_err(|_| invalid())?;
+                sign * (hours * 3600 + minutes * 60)
+            }
+        };
+        let local = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second;
+        Ok(ScheduleTime(local - offset))
+    }
+
+    ///
+    /// Seconds since the Unix epoch
+    ///
+    pub fn unix(&self) -> i64 {
+        self.0
+    }
+
+    ///
+    /// Return true when the minutes are :00, :15, :30 or :45 and there are no seconds
+    ///
+    pub fn is_quarter_hour(&self) -> bool {
+        self.0.rem_euclid(QUARTER_HOUR) == 0
+    }
+
+    ///
+    /// Round the instant to the quarter-hour
+    ///
+    pub fn round(&self, rule: QuarterHourRule) -> Self {
+        let down = self.0 - self.0.rem_euclid(QUARTER_HOUR);
+        let up = if self.is_quarter_hour() {
+            self.0
+        } else {
+            down + QUARTER_HOUR
+        };
+        match rule {
+            QuarterHourRule::Reject => *self,
+            QuarterHourRule::RoundDown => ScheduleTime(down),
+            QuarterHourRule::RoundUp => ScheduleTime(up),
+            QuarterHourRule::RoundNearest if self.0 - down < up - self.0 => ScheduleTime(down),
+            QuarterHourRule::RoundNearest => ScheduleTime(up),
+        }
+    }
+
+    ///
+    /// ISO 8601 format in UTC, e.g. `2020-06-01T12:15:00+00:00`
+    ///
+    pub fn to_iso8601(&self) -> String {
+        let days = self.0.div_euclid(86_400);
+        let seconds = self.0.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}+00:00",
+            year,
+            month,
+            day,
+            seconds / 3600,
+            (seconds % 3600) / 60,
+            seconds % 60
+        )
+    }
+}
+
+impl fmt::Display for ScheduleTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_iso8601())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ScheduleTime {
+    ///
+    /// The instant as `DateTime<Utc>`, `None` when it's out of the range of chrono
+    ///
+    pub fn to_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+        chrono::Utc.timestamp_opt(self.0, 0).single()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for ScheduleTime {
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        ScheduleTime(value.timestamp())
+    }
+}
+
+///
+/// What to do with a delivery time that isn't on the quarter-hour
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuarterHourRule {
+    /// Report an error
+    #[default]
+    Reject,
+    /// Use the previous quarter-hour
+    RoundDown,
+    /// Use the next quarter-hour
+    RoundUp,
+    /// Use the closest quarter-hour
+    RoundNearest,
+}
+
+///
+/// Reasons why a campaign can't be scheduled
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleError {
+    /// The date and time could not be parsed
+    InvalidTime(String),
+    /// The date and time has no timezone
+    MissingTimezone(String),
+    /// The delivery time is not on the quarter-hour
+    NotQuarterHour(ScheduleTime),
+    /// The delivery time is not in the future
+    InThePast(ScheduleTime),
+    /// Timewarp and Batch Delivery can't be used together
+    TimewarpWithBatchDelivery,
+    /// Timewarp campaigns must be scheduled at least 24 hours in advance
+    TimewarpLeadTime(ScheduleTime),
+    /// The batch delivery needs at least 2 batches and a delay
+    InvalidBatchDelivery(ScheduleBatchDelivery),
+}
+
+impl fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScheduleError::InvalidTime(v) => write!(f, "`{}` is not an ISO 8601 date and time", v),
+            ScheduleError::MissingTimezone(v) => {
+                write!(f, "`{}` has no timezone, add Z or an offset like +02:00", v)
+            }
+            ScheduleError::NotQuarterHour(t) => write!(
+                f,
+                "{} is not on the quarter-hour (:00, :15, :30, :45)",
+                t
+            ),
+            ScheduleError::InThePast(t) => write!(f, "{} is not in the future", t),
+            ScheduleError::TimewarpWithBatchDelivery => {
+                write!(f, "Timewarp can't be used with Batch Delivery")
+            }
+            ScheduleError::TimewarpLeadTime(t) => write!(
+                f,
+                "Timewarp campaigns must be scheduled at least 24 hours in advance, {} is too soon",
+                t
+            ),
+            ScheduleError::InvalidBatchDelivery(b) => write!(
+                f,
+                "Batch Delivery needs at least 2 batches and a delay, got {} batches every {} minutes",
+                b.batch_count, b.batch_delay
+            ),
+        }
+    }
+}
+
+impl Error for ScheduleError {}
+
+impl From<ScheduleError> for MailchimpErrorType {
+    fn from(value: ScheduleError) -> Self {
+        MailchimpErrorType::invalid_request(&value.to_string())
+    }
+}
+
+///
+/// Schedule of a campaign
+///
+#[derive(Debug, Clone)]
+pub struct CampaignSchedule {
+    time: ScheduleTime,
+    rounding: QuarterHourRule,
+    timewarp: bool,
+    batch_delivery: Option<ScheduleBatchDelivery>,
+}
+
+impl CampaignSchedule {
+    ///
+    /// Deliver the campaign at the given instant
+    ///
+    pub fn at<T: Into<ScheduleTime>>(time: T) -> Self {
+        CampaignSchedule {
+            time: time.into(),
+            rounding: QuarterHourRule::default(),
+            timewarp: false,
+            batch_delivery: None,
+        }
+    }
+
+    ///
+    /// What to do when the time isn't on the quarter-hour, rejected by default
+    ///
+    pub fn rounding(mut self, rule: QuarterHourRule) -> Self {
+        self.rounding = rule;
+        self
+    }
+
+    ///
+    /// Deliver at the scheduled time in the time zone of each recipient
+    ///
+    pub fn timewarp(mut self) -> Self {
+        self.timewarp = true;
+        self
+    }
+
+    ///
+    /// Deliver in batches
+    ///
+    /// Arguments:
+    ///     batch_count: The number of batches for the campaign send.
+    ///     batch_delay: The delay, in minutes, between batches.
+    ///
+    pub fn batch_delivery(mut self, batch_count: u64, batch_delay: u64) -> Self {
+        self.batch_delivery = Some(ScheduleBatchDelivery {
+            batch_delay,
+            batch_count,
+        });
+        self
+    }
+
+    ///
+    /// The delivery time, rounded to the quarter-hour when a rounding is set
+    ///
+    pub fn time(&self) -> ScheduleTime {
+        self.time.round(self.rounding)
+    }
+
+    ///
+    /// Check the schedule against the current time and return the params
+    ///
+    pub fn build(&self) -> Result<ScheduleParam, ScheduleError> {
+        self.build_at(ScheduleTime::now())
+    }
+
+    ///
+    /// Check the schedule against the given current time and return the params
+    ///
+    pub fn build_at(&self, now: ScheduleTime) -> Result<ScheduleParam, ScheduleError> {
+        let time = self.time();
+        if !time.is_quarter_hour() {
+            return Err(ScheduleError::NotQuarterHour(time));
+        }
+        if time <= now {
+            return Err(ScheduleError::InThePast(time));
+        }
+        if let Some(batch) = &self.batch_delivery {
+            if self.timewarp {
+                return Err(ScheduleError::TimewarpWithBatchDelivery);
+            }
+            if batch.batch_count < 2 || batch.batch_delay == 0 {
+                return Err(ScheduleError::InvalidBatchDelivery(batch.clone()));
+            }
+        }
+        if self.timewarp && time.unix() - now.unix() < TIMEWARP_LEAD_TIME {
+            return Err(ScheduleError::TimewarpLeadTime(time));
+        }
+        Ok(ScheduleParam {
+            schedule_time: time.to_iso8601(),
+            timewarp: self.timewarp,
+            batch_delivery: self.batch_delivery.clone(),
+        })
+    }
+}
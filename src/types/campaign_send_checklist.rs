@@ -50,3 +50,46 @@ pub struct SendChecklistType {
     #[serde(default)]
     pub _links: Vec<LinkType>,
 }
+
+impl ChecklistItem {
+    ///
+    /// Return true when the item must be resolved before sending
+    ///
+    pub fn is_error(&self) -> bool {
+        self.item_type == "error"
+    }
+
+    ///
+    /// Return true when the item is a warning
+    ///
+    pub fn is_warning(&self) -> bool {
+        self.item_type == "warning"
+    }
+}
+
+impl SendChecklistType {
+    ///
+    /// Items of type error
+    ///
+    pub fn errors(&self) -> Vec<&ChecklistItem> {
+        self.items.iter().filter(|i| i.is_error()).collect()
+    }
+
+    ///
+    /// Items of type warning
+    ///
+    pub fn warnings(&self) -> Vec<&ChecklistItem> {
+        self.items.iter().filter(|i| i.is_warning()).collect()
+    }
+
+    ///
+    /// Items that prevent sending the campaign: the errors and, when
+    /// `include_warnings` is true, also the warnings
+    ///
+    pub fn blocking_items(&self, include_warnings: bool) -> Vec<&ChecklistItem> {
+        self.items
+            .iter()
+            .filter(|i| i.is_error() || (include_warnings && i.is_warning()))
+            .collect()
+    }
+}
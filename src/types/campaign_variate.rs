@@ -0,0 +1,416 @@
+//! Typed configuration of Multivariate Campaigns and the report of their
+//! combinations, see `CampaignBuilder::variate` and `CampaignType::variate_report`
+//!
+//! Mailchimp tests up to 3 variations of each variable and up to 8
+//! combinations in total. These limits are checked locally.
+//!
+//! ```
+//!     use mailchimp::types::{VariateConfig, WinnerCriteria};
+//!
+//!     let config = VariateConfig::new()
+//!         .subject_line("Jokes of the week")
+//!         .subject_line("Your weekly laugh")
+//!         .content("Long jokes")
+//!         .content("Short jokes")
+//!         .winner_criteria(WinnerCriteria::Clicks)
+//!         .wait_hours(4)
+//!         .test_size(30);
+//!     assert_eq!(config.combination_count(), 4);
+//!
+//!     let settings = config.build().unwrap();
+//!     assert_eq!(settings.wait_time, Some(240));
+//! ```
+//!
+
+use super::campaign::{CombinationsType, VariateSettingsType};
+use super::campaign_schedule::ScheduleTime;
+use super::report::ReportType;
+use super::status::WinnerCriteria;
+use crate::internal::error_type::MailchimpErrorType;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+/// Maximum number of variations of each variable
+pub const MAX_VARIATIONS: usize = 3;
+/// Maximum number of combinations of a campaign
+pub const MAX_COMBINATIONS: usize = 8;
+
+///
+/// Reasons why the variate settings are rejected
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VariateError {
+    /// No variable has at least two variations
+    NothingToTest,
+    /// A variable has more than `MAX_VARIATIONS` variations
+    TooManyVariations {
+        /// The variable, e.g. `subject_lines`
+        variable: String,
+        /// The number of variations
+        count: usize,
+    },
+    /// The variations make more than `MAX_COMBINATIONS` combinations
+    TooManyCombinations(usize),
+    /// The number of from names doesn't match the number of reply-to addresses
+    FromNamesWithoutReplyTo,
+    /// The test size is not between 10 and 100
+    InvalidTestSize(u64),
+    /// The wait time is not a positive number of whole hours, in minutes
+    InvalidWaitTime(u64),
+    /// A send time is not on the quarter-hour
+    SendTimeNotQuarterHour(ScheduleTime),
+}
+
+impl fmt::Display for VariateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VariateError::NothingToTest => write!(
+                f,
+                "a variate campaign must test at least two subject lines, send times, from names or contents"
+            ),
+            VariateError::TooManyVariations { variable, count } => write!(
+                f,
+                "{} has {} variations, the limit is {}",
+                variable, count, MAX_VARIATIONS
+            ),
+            VariateError::TooManyCombinations(count) => write!(
+                f,
+                "the variations make {} combinations, the limit is {}",
+                count, MAX_COMBINATIONS
+            ),
+            VariateError::FromNamesWithoutReplyTo => write!(
+                f,
+                "the number of from names must match the number of reply-to addresses"
+            ),
+            VariateError::InvalidTestSize(size) => {
+                write!(f, "the test size must be between 10 and 100, got {}", size)
+            }
+            VariateError::InvalidWaitTime(minutes) => write!(
+                f,
+                "the wait time must be a positive number of whole hours, got {} minutes",
+                minutes
+            ),
+            VariateError::SendTimeNotQuarterHour(t) => write!(
+                f,
+                "the send time {} is not on the quarter-hour (:00, :15, :30, :45)",
+                t
+            ),
+        }
+    }
+}
+
+impl Error for VariateError {}
+
+impl From<VariateError> for MailchimpErrorType {
+    fn from(value: VariateError) -> Self {
+        MailchimpErrorType::invalid_request(&value.to_string())
+    }
+}
+
+fn count(values: &Option<Vec<String>>) -> usize {
+    values.as_ref().map(|l| l.len()).unwrap_or(0)
+}
+
+///
+/// Check the variate settings against the limits of Mailchimp
+///
+pub fn check_variate_settings(settings: &VariateSettingsType) -> Result<(), VariateError> {
+    let variables = [
+        ("subject_lines", count(&settings.subject_lines)),
+        ("send_times", count(&settings.send_times)),
+        ("from_names", count(&settings.from_names)),
+        ("contents", count(&settings.contents)),
+    ];
+    if let Some((variable, count)) = variables.iter().find(|(_, c)| *c > MAX_VARIATIONS) {
+        return Err(VariateError::TooManyVariations {
+            variable: variable.to_string(),
+            count: *count,
+        });
+    }
+    if variables.iter().all(|(_, c)| *c < 2) {
+        return Err(VariateError::NothingToTest);
+    }
+    if count(&settings.from_names) != count(&settings.reply_to_addresses) {
+        return Err(VariateError::FromNamesWithoutReplyTo);
+    }
+    let combinations = combination_count(settings);
+    if combinations > MAX_COMBINATIONS {
+        return Err(VariateError::TooManyCombinations(combinations));
+    }
+    if let Some(size) = settings.test_size {
+        if !(10..=100).contains(&size) {
+            return Err(VariateError::InvalidTestSize(size));
+        }
+    }
+    if let Some(minutes) = settings.wait_time {
+        if minutes == 0 || minutes % 60 != 0 {
+            return Err(VariateError::InvalidWaitTime(minutes));
+        }
+    }
+    Ok(())
+}
+
+///
+/// Number of combinations of the variate settings
+///
+pub fn combination_count(settings: &VariateSettingsType) -> usize {
+    [
+        &settings.subject_lines,
+        &settings.send_times,
+        &settings.from_names,
+        &settings.contents,
+    ]
+    .iter()
+    .map(|values| count(values).max(1))
+    .product()
+}
+
+///
+/// Typed variate settings, built with `VariateConfig::build`
+///
+#[derive(Debug, Clone, Default)]
+pub struct VariateConfig {
+    subject_lines: Vec<String>,
+    from_names: Vec<(String, String)>,
+    send_times: Vec<ScheduleTime>,
+    contents: Vec<String>,
+    winner_criteria: Option<WinnerCriteria>,
+    wait_hours: Option<u64>,
+    test_size: Option<u64>,
+}
+
+impl VariateConfig {
+    ///
+    /// Empty configuration, add at least two variations of a variable
+    ///
+    pub fn new() -> Self {
+        VariateConfig::default()
+    }
+
+    ///
+    /// Subject line to test
+    ///
+    pub fn subject_line(mut self, subject_line: &str) -> Self {
+        self.subject_lines.push(subject_line.to_string());
+        self
+    }
+
+    ///
+    /// From name to test, with its reply-to address
+    ///
+    pub fn from_name(mut self, from_name: &str, reply_to: &str) -> Self {
+        self.from_names
+            .push((from_name.to_string(), reply_to.to_string()));
+        self
+    }
+
+    ///
+    /// Send time to test. When send times are tested the test size is 100% and
+    /// the winner criteria is ignored.
+    ///
+    pub fn send_time<T: Into<ScheduleTime>>(mut self, send_time: T) -> Self {
+        self.send_times.push(send_time.into());
+        self
+    }
+
+    ///
+    /// Description of a content to test, the contents are set with
+    /// `CampaignContent::variate` in the same order
+    ///
+    pub fn content(mut self, description: &str) -> Self {
+        self.contents.push(description.to_string());
+        self
+    }
+
+    ///
+    /// How the winning combination is chosen
+    ///
+    pub fn winner_criteria(mut self, criteria: WinnerCriteria) -> Self {
+        self.winner_criteria = Some(criteria);
+        self
+    }
+
+    ///
+    /// Hours to wait before choosing the winning combination
+    ///
+    pub fn wait_hours(mut self, hours: u64) -> Self {
+        self.wait_hours = Some(hours);
+        self
+    }
+
+    ///
+    /// Percentage of the recipients that receive the test combinations, 10 to 100
+    ///
+    pub fn test_size(mut self, percent: u64) -> Self {
+        self.test_size = Some(percent);
+        self
+    }
+
+    ///
+    /// Number of combinations that will be sent
+    ///
+    pub fn combination_count(&self) -> usize {
+        [
+            self.subject_lines.len(),
+            self.from_names.len(),
+            self.send_times.len(),
+            self.contents.len(),
+        ]
+        .iter()
+        .map(|c| (*c).max(1))
+        .product()
+    }
+
+    ///
+    /// Check the limits and return the variate settings of the campaign
+    ///
+    pub fn build(&self) -> Result<VariateSettingsType, VariateError> {
+        if let Some(t) = self.send_times.iter().find(|t| !t.is_quarter_hour()) {
+            return Err(VariateError::SendTimeNotQuarterHour(*t));
+        }
+        let list = |values: Vec<String>| {
+            if values.is_empty() {
+                None
+            } else {
+                Some(values)
+            }
+        };
+        let testing_send_times = !self.send_times.is_empty();
+        let settings = VariateSettingsType {
+            winner_criteria: if testing_send_times {
+                None
+            } else {
+                self.winner_criteria.clone()
+            },
+            wait_time: self.wait_hours.map(|h| h * 60),
+            test_size: if testing_send_times {
+                Some(100)
+            } else {
+                self.test_size
+            },
+            subject_lines: list(self.subject_lines.clone()),
+            send_times: list(
+                self.send_times
+                    .iter()
+                    .map(|t| t.to_iso8601()[..19].replace('T', " "))
+                    .collect(),
+            ),
+            from_names: list(self.from_names.iter().map(|f| f.0.clone()).collect()),
+            reply_to_addresses: list(self.from_names.iter().map(|f| f.1.clone()).collect()),
+            contents: list(self.contents.clone()),
+            ..VariateSettingsType::default()
+        };
+        check_variate_settings(&settings)?;
+        Ok(settings)
+    }
+}
+
+///
+/// A combination of a Multivariate Campaign with the variations it sent
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct VariateCombinationReport {
+    /// Unique ID for the combination.
+    pub id: String,
+    /// The subject line tested, if any.
+    pub subject_line: Option<String>,
+    /// The from name tested, if any.
+    pub from_name: Option<String>,
+    /// The reply-to address tested, if any.
+    pub reply_to: Option<String>,
+    /// The send time tested, if any.
+    pub send_time: Option<String>,
+    /// The description of the content tested, if any.
+    pub content: Option<String>,
+    /// The number of recipients for this combination.
+    pub recipients: u64,
+    /// Whether this combination won the test.
+    pub is_winner: bool,
+}
+
+///
+/// Report of a Multivariate Campaign by combination
+///
+/// The campaign report of Mailchimp has the totals of the whole campaign; the
+/// combinations, their recipients and the winner come from the variate
+/// settings of the campaign.
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct VariateReport {
+    /// The id of the campaign.
+    pub campaign_id: String,
+    /// How the winner was chosen.
+    pub winner_criteria: Option<WinnerCriteria>,
+    /// ID for the winning combination.
+    pub winning_combination_id: Option<String>,
+    /// ID of the campaign sent to the remaining recipients with the winning combination.
+    pub winning_campaign_id: Option<String>,
+    /// Every combination of the test.
+    pub combinations: Vec<VariateCombinationReport>,
+    /// The total number of emails sent.
+    pub emails_sent: u64,
+    /// The number of unique opens.
+    pub unique_opens: u64,
+    /// The number of unique opens divided by the total number of successful deliveries.
+    pub open_rate: f32,
+    /// The number of unique clicks.
+    pub unique_clicks: u64,
+    /// The number of unique clicks divided by the total number of successful deliveries.
+    pub click_rate: f32,
+    /// The total revenue of the campaign.
+    pub total_revenue: f32,
+}
+
+impl VariateReport {
+    ///
+    /// Map the variate settings of a campaign and its report
+    ///
+    pub fn new(settings: &VariateSettingsType, report: &ReportType) -> Self {
+        let pick = |values: &Option<Vec<String>>, index: Option<u64>| {
+            values
+                .as_ref()
+                .and_then(|v| index.and_then(|i| v.get(i as usize)))
+                .cloned()
+        };
+        let combinations = settings
+            .combinations
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .map(|c: &CombinationsType| {
+                let id = c.id.clone().unwrap_or_default();
+                VariateCombinationReport {
+                    is_winner: settings.winning_combination_id.as_ref() == Some(&id),
+                    id,
+                    subject_line: pick(&settings.subject_lines, c.subject_line),
+                    from_name: pick(&settings.from_names, c.from_name),
+                    reply_to: pick(&settings.reply_to_addresses, c.reply_to),
+                    send_time: pick(&settings.send_times, c.send_time),
+                    content: pick(&settings.contents, c.content_description),
+                    recipients: c.recipients.unwrap_or(0),
+                }
+            })
+            .collect();
+        VariateReport {
+            campaign_id: report.id.clone(),
+            winner_criteria: settings.winner_criteria.clone(),
+            winning_combination_id: settings.winning_combination_id.clone(),
+            winning_campaign_id: settings.winning_campaign_id.clone(),
+            combinations,
+            emails_sent: report.emails_sent,
+            unique_opens: report.opens.unique_opens,
+            open_rate: report.opens.open_rate,
+            unique_clicks: report.clicks.unique_clicks,
+            click_rate: report.clicks.click_rate,
+            total_revenue: report.ecommerce.total_revenue,
+        }
+    }
+
+    ///
+    /// The winning combination, once chosen
+    ///
+    pub fn winner(&self) -> Option<&VariateCombinationReport> {
+        self.combinations.iter().find(|c| c.is_winner)
+    }
+}
@@ -0,0 +1,336 @@
+//! Compose and send a campaign in one operation, see `Campaigns::compose_and_send`
+//!
+//! The steps are run in order and the workflow stops at the first step that
+//! fails. The result describes every step, including the ones skipped.
+//!
+
+use super::campaign::{CampaignType, EmailParam};
+use super::campaign_content::CampaignContentParam;
+use super::campaign_schedule::CampaignSchedule;
+use super::campaign_send_checklist::{ChecklistItem, SendChecklistType};
+use crate::internal::error_type::MailchimpErrorType;
+use serde::{Deserialize, Serialize};
+
+///
+/// What to do with the campaign once the checklist and the test emails passed
+///
+#[derive(Debug, Clone)]
+pub enum CampaignDelivery {
+    /// Send the campaign immediately
+    SendNow,
+    /// Schedule the campaign for delivery, the schedule is checked before
+    /// it's sent, see `CampaignType::schedule`
+    Schedule(CampaignSchedule),
+    /// Keep the campaign as a draft, e.g. to review the test emails
+    Draft,
+}
+
+///
+/// Steps of the compose and send workflow
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowStepKind {
+    /// Create the campaign
+    Create,
+    /// Set the content of the campaign
+    Content,
+    /// Review the send checklist
+    Checklist,
+    /// Send the test emails
+    TestEmail,
+    /// Send or schedule the campaign
+    Delivery,
+}
+
+///
+/// Result of a step of the workflow
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowStepStatus {
+    /// The step was completed
+    Done,
+    /// The step failed, the workflow stopped here
+    Failed,
+    /// The step was not run
+    Skipped,
+}
+
+///
+/// Step of the workflow and its outcome
+///
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WorkflowStep {
+    /// The step.
+    pub step: WorkflowStepKind,
+    /// The outcome of the step.
+    pub status: WorkflowStepStatus,
+    /// Human readable details of the outcome.
+    pub detail: String,
+    /// The error returned by the API, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<MailchimpErrorType>,
+}
+
+///
+/// Result of the compose and send workflow
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ComposeAndSendResult {
+    /// The id of the campaign, when it was created.
+    pub campaign_id: Option<String>,
+    /// Every step of the workflow, in order.
+    pub steps: Vec<WorkflowStep>,
+    /// Items of the send checklist that stopped the workflow.
+    pub blocking_items: Vec<ChecklistItem>,
+}
+
+impl ComposeAndSendResult {
+    fn add(
+        &mut self,
+        step: WorkflowStepKind,
+        status: WorkflowStepStatus,
+        detail: &str,
+        error: Option<MailchimpErrorType>,
+    ) {
+        self.steps.push(WorkflowStep {
+            step,
+            status,
+            detail: detail.to_string(),
+            error,
+        });
+    }
+
+    ///
+    /// Record a step completed
+    ///
+    pub(crate) fn done(&mut self, step: WorkflowStepKind, detail: &str) {
+        self.add(step, WorkflowStepStatus::Done, detail, None)
+    }
+
+    ///
+    /// Record a step that failed
+    ///
+    pub(crate) fn failed(
+        &mut self,
+        step: WorkflowStepKind,
+        detail: &str,
+        error: Option<MailchimpErrorType>,
+    ) {
+        self.add(step, WorkflowStepStatus::Failed, detail, error)
+    }
+
+    ///
+    /// Record the steps that were not run after a failure
+    ///
+    pub(crate) fn skip_remaining(&mut self) {
+        let all = [
+            WorkflowStepKind::Create,
+            WorkflowStepKind::Content,
+            WorkflowStepKind::Checklist,
+            WorkflowStepKind::TestEmail,
+            WorkflowStepKind::Delivery,
+        ];
+        for step in all.iter() {
+            if !self.steps.iter().any(|s| s.step == *step) {
+                self.add(*step, WorkflowStepStatus::Skipped, "not run", None);
+            }
+        }
+    }
+
+    ///
+    /// Return true when no step failed
+    ///
+    pub fn is_success(&self) -> bool {
+        self.failed_step().is_none()
+    }
+
+    ///
+    /// The step that stopped the workflow
+    ///
+    pub fn failed_step(&self) -> Option<&WorkflowStep> {
+        self.steps
+            .iter()
+            .find(|s| s.status == WorkflowStepStatus::Failed)
+    }
+
+    ///
+    /// One line per step, to post the result
+    ///
+    pub fn summary(&self) -> String {
+        self.steps
+            .iter()
+            .map(|s| {
+                let status = match s.status {
+                    WorkflowStepStatus::Done => "done",
+                    WorkflowStepStatus::Failed => "FAILED",
+                    WorkflowStepStatus::Skipped => "skipped",
+                };
+                format!("{:?}: {} - {}", s.step, status, s.detail)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+///
+/// Plan of the compose and send workflow
+///
+#[derive(Debug, Clone)]
+pub struct ComposeAndSend {
+    content: CampaignContentParam,
+    test_emails: Vec<String>,
+    test_send_type: String,
+    block_on_warnings: bool,
+    delivery: CampaignDelivery,
+}
+
+impl ComposeAndSend {
+    ///
+    /// New plan that sets the content and sends the campaign immediately, see
+    /// `CampaignContent`
+    ///
+    pub fn new<C: Into<CampaignContentParam>>(content: C) -> Self {
+        ComposeAndSend {
+            content: content.into(),
+            test_emails: Vec::new(),
+            test_send_type: "html".to_string(),
+            block_on_warnings: false,
+            delivery: CampaignDelivery::SendNow,
+        }
+    }
+
+    ///
+    /// Send test emails before the delivery
+    ///
+    /// Arguments:
+    ///     test_emails: Email addresses to send the test email to
+    ///     send_type: html or plaintext
+    ///
+    pub fn test_emails(mut self, test_emails: Vec<String>, send_type: &str) -> Self {
+        self.test_emails = test_emails;
+        self.test_send_type = send_type.to_string();
+        self
+    }
+
+    ///
+    /// Stop the workflow also when the send checklist has warnings
+    ///
+    pub fn block_on_warnings(mut self, block: bool) -> Self {
+        self.block_on_warnings = block;
+        self
+    }
+
+    ///
+    /// What to do once the campaign is ready
+    ///
+    pub fn delivery(mut self, delivery: CampaignDelivery) -> Self {
+        self.delivery = delivery;
+        self
+    }
+
+    ///
+    /// Items of the checklist that stop the workflow. When the checklist is
+    /// not ready and has no error, the checklist itself is the blocking item.
+    ///
+    pub fn blocking_items(&self, checklist: &SendChecklistType) -> Vec<ChecklistItem> {
+        let mut items: Vec<ChecklistItem> = checklist
+            .blocking_items(self.block_on_warnings)
+            .into_iter()
+            .cloned()
+            .collect();
+        if items.is_empty() && !checklist.is_ready {
+            items.push(ChecklistItem {
+                item_type: "error".to_string(),
+                heading: "The campaign is not ready to send".to_string(),
+                ..ChecklistItem::default()
+            });
+        }
+        items
+    }
+
+    ///
+    /// Run the workflow on a campaign already created: set the content,
+    /// review the send checklist, send the test emails and deliver the campaign.
+    ///
+    pub fn run(&self, campaign: &CampaignType, result: &mut ComposeAndSendResult) {
+        result.campaign_id = campaign.id.clone();
+
+        if let Err(e) = campaign.update_content(self.content.clone()) {
+            result.failed(
+                WorkflowStepKind::Content,
+                "the content was rejected",
+                Some(e),
+            );
+            return result.skip_remaining();
+        }
+        result.done(WorkflowStepKind::Content, "content set");
+
+        let checklist = match campaign.send_checklist(None, None) {
+            Ok(checklist) => checklist,
+            Err(e) => {
+                result.failed(
+                    WorkflowStepKind::Checklist,
+                    "the send checklist could not be read",
+                    Some(e),
+                );
+                return result.skip_remaining();
+            }
+        };
+        let blocking = self.blocking_items(&checklist);
+        if !blocking.is_empty() {
+            let headings: Vec<&str> = blocking.iter().map(|i| i.heading.as_str()).collect();
+            result.failed(
+                WorkflowStepKind::Checklist,
+                &format!("blocked by: {}", headings.join("; ")),
+                None,
+            );
+            result.blocking_items = blocking;
+            return result.skip_remaining();
+        }
+        result.done(
+            WorkflowStepKind::Checklist,
+            &format!("ready, {} warnings", checklist.warnings().len()),
+        );
+
+        if self.test_emails.is_empty() {
+            result.add(
+                WorkflowStepKind::TestEmail,
+                WorkflowStepStatus::Skipped,
+                "no test emails",
+                None,
+            );
+        } else {
+            let param = EmailParam::new(self.test_emails.clone(), self.test_send_type.clone());
+            if let Err(e) = campaign.send_test_email(param) {
+                result.failed(
+                    WorkflowStepKind::TestEmail,
+                    "the test emails were not sent",
+                    Some(e),
+                );
+                return result.skip_remaining();
+            }
+            result.done(
+                WorkflowStepKind::TestEmail,
+                &format!("sent to {}", self.test_emails.join(", ")),
+            );
+        }
+
+        let delivered = match &self.delivery {
+            CampaignDelivery::SendNow => campaign.send_campaign().map(|_| "sent".to_string()),
+            CampaignDelivery::Schedule(schedule) => campaign
+                .schedule(schedule)
+                .map(|_| format!("scheduled for {}", schedule.time().to_iso8601())),
+            CampaignDelivery::Draft => Ok("kept as draft".to_string()),
+        };
+        match delivered {
+            Ok(detail) => result.done(WorkflowStepKind::Delivery, &detail),
+            Err(e) => result.failed(
+                WorkflowStepKind::Delivery,
+                "the campaign was not delivered",
+                Some(e),
+            ),
+        }
+    }
+}
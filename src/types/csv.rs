@@ -0,0 +1,23 @@
+//! Helpers to write the CSV exports, see `write_consent_csv` and
+//! `write_reports_csv`
+//!
+
+///
+/// Quote a CSV field when it contains a separator, a quote or a line break.
+/// Values that a spreadsheet would run as a formula (starting with `=`, `+`,
+/// `-`, `@`, a tab or a carriage return) are prefixed with `'`, numbers are
+/// kept as they are.
+///
+pub(crate) fn csv_field(value: &str) -> String {
+    let formula = value.starts_with(|c| matches!(c, '=' | '+' | '-' | '@' | '\t' | '\r'));
+    let value = if formula && value.parse::<f64>().is_err() {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains(',') || value.contains('"') || value.contains('\n') || value.contains('\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
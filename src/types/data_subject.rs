@@ -0,0 +1,194 @@
+//! Types of the data subject requests (GDPR): the export of everything stored
+//! about a person and the report of its erasure, see `Lists::export_member_data`
+//! and `Lists::erase_member`.
+//!
+
+use super::list_member_activity_feed::MemberActivityFeedItem;
+use super::list_member_events::ListMemberEvent;
+use super::list_member_notes::ListMemberNote;
+use super::list_members::ListMember;
+use super::subscriber_hash::SubscriberHash;
+use crate::internal::error_type::MailchimpErrorType;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+///
+/// Everything stored about a person in one list
+///
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MemberDataExport {
+    /// The list id.
+    pub list_id: String,
+    /// The name of the list.
+    pub list_name: String,
+    /// The member of the list.
+    pub member: ListMember,
+    /// The notes of the member.
+    pub notes: Vec<ListMemberNote>,
+    /// The tags of the member.
+    pub tags: Vec<String>,
+    /// The activity feed of the member.
+    pub activity: Vec<MemberActivityFeedItem>,
+    /// The custom events of the member.
+    pub events: Vec<ListMemberEvent>,
+}
+
+///
+/// Everything stored about a person across all the lists of the account
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DataSubjectExport {
+    /// The email address of the person.
+    pub email_address: String,
+    /// The subscriber hash of the email address.
+    pub subscriber_hash: SubscriberHash,
+    /// One entry for each list where the person is a member.
+    pub memberships: Vec<MemberDataExport>,
+    /// Lists where the membership could not be read completely, with the
+    /// reason. An empty list id means the lists of the account could not be read.
+    pub failed: Vec<(String, MailchimpErrorType)>,
+}
+
+impl DataSubjectExport {
+    ///
+    /// New empty export for the email address
+    ///
+    pub fn new(email_address: &str) -> Self {
+        DataSubjectExport {
+            email_address: email_address.trim().to_string(),
+            subscriber_hash: SubscriberHash::from_email(email_address),
+            memberships: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+
+    ///
+    /// Ids of the lists where the person is a member
+    ///
+    pub fn list_ids(&self) -> Vec<String> {
+        self.memberships.iter().map(|m| m.list_id.clone()).collect()
+    }
+
+    ///
+    /// The bundle as pretty printed JSON, to hand over to the person
+    ///
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    ///
+    /// MD5 digest of the bundle, used to prove which data was exported. It is
+    /// computed over compact JSON with the keys of every object sorted, so the
+    /// digest of a reloaded export is the same.
+    ///
+    pub fn digest(&self) -> serde_json::Result<String> {
+        let json = canonical_json(&serde_json::to_value(self)?)?;
+        Ok(format!("{:x}", md5::compute(json.as_bytes())))
+    }
+}
+
+///
+/// Compact JSON with the keys of every object sorted. The order is set here,
+/// the order of `serde_json::Map` depends on its `preserve_order` feature.
+///
+pub(crate) fn canonical_json(value: &Value) -> serde_json::Result<String> {
+    Ok(match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let mut fields = Vec::with_capacity(keys.len());
+            for key in keys {
+                fields.push(format!(
+                    "{}:{}",
+                    serde_json::to_string(key)?,
+                    canonical_json(&map[key])?
+                ));
+            }
+            format!("{{{}}}", fields.join(","))
+        }
+        Value::Array(items) => {
+            let items = items
+                .iter()
+                .map(canonical_json)
+                .collect::<serde_json::Result<Vec<String>>>()?;
+            format!("[{}]", items.join(","))
+        }
+        other => serde_json::to_string(other)?,
+    })
+}
+
+///
+/// Report of the permanent erasure of a person
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ErasureReport {
+    /// The email address of the person.
+    pub email_address: String,
+    /// The subscriber hash of the email address.
+    pub subscriber_hash: SubscriberHash,
+    /// MD5 digest of the data exported before the erasure, see `DataSubjectExport::digest`.
+    pub export_digest: String,
+    /// Ids of the lists where the member was permanently deleted.
+    pub erased: Vec<String>,
+    /// Lists where the member could not be read or deleted, with the reason.
+    pub failed: Vec<(String, MailchimpErrorType)>,
+}
+
+impl ErasureReport {
+    ///
+    /// Start the report from the export taken before the erasure. The lists
+    /// that could not be read are already failures. Fails when the digest of
+    /// the export can not be computed.
+    ///
+    pub fn from_export(export: &DataSubjectExport) -> serde_json::Result<Self> {
+        Ok(ErasureReport {
+            email_address: export.email_address.clone(),
+            subscriber_hash: export.subscriber_hash.clone(),
+            export_digest: export.digest()?,
+            erased: Vec::new(),
+            failed: export.failed.clone(),
+        })
+    }
+
+    ///
+    /// Record the result of `ListMember::permanently_delete` for a list
+    ///
+    pub fn add_result(&mut self, list_id: &str, error: Option<MailchimpErrorType>) {
+        match error {
+            None => self.erased.push(list_id.to_string()),
+            Some(e) => self.failed.push((list_id.to_string(), e)),
+        }
+    }
+
+    ///
+    /// Return true when the person was erased from every list
+    ///
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    ///
+    /// Human readable summary of the report, to sign-off the request
+    ///
+    pub fn summary(&self) -> String {
+        let mut lines = vec![
+            format!(
+                "Erasure of {} ({})",
+                self.email_address, self.subscriber_hash
+            ),
+            format!("Export digest: {}", self.export_digest),
+        ];
+        for list_id in &self.erased {
+            lines.push(format!("  removed from {}", list_id));
+        }
+        for (list_id, e) in &self.failed {
+            lines.push(format!("  FAILED {}: {}", list_id, e));
+        }
+        lines.push(if self.is_complete() {
+            "Status: complete".to_string()
+        } else {
+            format!("Status: incomplete, {} failures", self.failed.len())
+        });
+        lines.join("\n")
+    }
+}
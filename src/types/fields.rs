@@ -0,0 +1,130 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+///
+/// Resource that supports field projection through the `fields` and
+/// `exclude_fields` query parameters.
+///
+pub trait FieldProjection {
+    ///
+    /// Name of the array that contains this resource inside its collection
+    /// response, e.g. `members` for `/lists/{list_id}/members`
+    ///
+    fn collection_key() -> &'static str;
+}
+
+///
+/// Field Selector
+///
+/// Typed builder for the `fields` / `exclude_fields` parameters. The available
+/// paths are exposed as methods on `FieldSelector<Resource>` so a typo becomes
+/// a compile error instead of a silently full payload.
+///
+/// ```
+///     use mailchimp::types::ListMember;
+///
+///     let fields = ListMember::fields().email_address().merge_fields();
+///     assert_eq!(fields.build(), "email_address,merge_fields");
+///     assert_eq!(
+///         fields.build_for_collection(),
+///         "members.email_address,members.merge_fields,total_items"
+///     );
+/// ```
+///
+pub struct FieldSelector<R> {
+    paths: Vec<&'static str>,
+    _resource: PhantomData<R>,
+}
+
+impl<R: FieldProjection> FieldSelector<R> {
+    ///
+    /// Empty selection
+    ///
+    pub fn new() -> Self {
+        FieldSelector {
+            paths: Vec::new(),
+            _resource: PhantomData,
+        }
+    }
+
+    ///
+    /// Add a path to the selection. Only used by the typed methods.
+    ///
+    pub(crate) fn with(mut self, path: &'static str) -> Self {
+        if !self.paths.contains(&path) {
+            self.paths.push(path);
+        }
+        self
+    }
+
+    ///
+    /// Return true when no field was selected
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    ///
+    /// Selected paths, in the order they were added
+    ///
+    pub fn paths(&self) -> &[&'static str] {
+        &self.paths
+    }
+
+    ///
+    /// Query value for a single resource request
+    /// (e.g. `email_address,merge_fields`)
+    ///
+    pub fn build(&self) -> String {
+        self.paths.join(",")
+    }
+
+    ///
+    /// Query value for a collection request. Each path is prefixed with the
+    /// collection key and `total_items` is always kept, because the iterators
+    /// need it to paginate.
+    ///
+    pub fn build_for_collection(&self) -> String {
+        let mut paths: Vec<String> = self
+            .paths
+            .iter()
+            .map(|p| format!("{}.{}", R::collection_key(), p))
+            .collect();
+        paths.push("total_items".to_string());
+        paths.join(",")
+    }
+
+    ///
+    /// Query value to exclude the selected paths from a collection request
+    ///
+    pub fn build_exclude_for_collection(&self) -> String {
+        self.paths
+            .iter()
+            .map(|p| format!("{}.{}", R::collection_key(), p))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+impl<R: FieldProjection> Default for FieldSelector<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> Clone for FieldSelector<R> {
+    fn clone(&self) -> Self {
+        FieldSelector {
+            paths: self.paths.clone(),
+            _resource: PhantomData,
+        }
+    }
+}
+
+impl<R> fmt::Debug for FieldSelector<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FieldSelector")
+            .field("paths", &self.paths)
+            .finish()
+    }
+}
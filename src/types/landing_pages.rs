@@ -2,6 +2,7 @@ use super::empty::EmptyType;
 use super::link::LinkType;
 use crate::api::MailchimpApi;
 use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
 use serde::{Deserialize, Serialize};
 
@@ -107,13 +108,266 @@ impl Default for CollectionLandingPage {
     }
 }
 
+///
+/// The tracking settings applied to a landing page
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LandingPageTracking {
+    /// Use cookies to track unique visitors and calculate overall conversion rate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub track_with_mailchimp: Option<bool>,
+    /// Google Restricted Data Processing for the Google Analytics tracking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_restricted_data_processing: Option<bool>,
+}
+
+///
+/// Params to create or update a landing page
+///
+/// Endpoints
+///     POST /landing-pages
+///     PATCH /landing-pages/{page_id}
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LandingPageParam {
+    /// The name of this landing page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The title of this landing page seen in the browser’s title bar.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The description of this landing page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The ID of the store associated with this landing page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub store_id: Option<String>,
+    /// The list’s ID associated with this landing page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_id: Option<String>,
+    /// The type of landing page, signup or product. Only on creation.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub page_type: Option<String>,
+    /// The template_id of this landing page. Only on creation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<u64>,
+    /// The tracking settings applied to this landing page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking: Option<LandingPageTracking>,
+}
+
+impl LandingPageParam {
+    ///
+    /// Params of a new signup landing page
+    ///
+    /// Arguments:
+    ///     name: The name of the landing page
+    ///     list_id: The list that receives the signups
+    ///     template_id: The template of the landing page
+    ///
+    pub fn new(name: &str, list_id: &str, template_id: u64) -> Self {
+        LandingPageParam {
+            name: Some(name.to_string()),
+            list_id: Some(list_id.to_string()),
+            page_type: Some("signup".to_string()),
+            template_id: Some(template_id),
+            ..LandingPageParam::default()
+        }
+    }
+
+    ///
+    /// Check the params required to create a landing page
+    ///
+    pub fn validate_create(&self) -> Result<(), MailchimpErrorType> {
+        let blank = |v: &Option<String>| v.as_ref().map(|v| v.trim().is_empty()).unwrap_or(true);
+        if blank(&self.list_id) {
+            return Err(MailchimpErrorType::invalid_request(
+                "the list of the landing page is required",
+            ));
+        }
+        if self.template_id.is_none() {
+            return Err(MailchimpErrorType::invalid_request(
+                "the template of the landing page is required",
+            ));
+        }
+        Ok(())
+    }
+}
+
+///
+/// The HTML and the JSON content of a landing page
+///
+/// Endpoint
+///     GET /landing-pages/{page_id}/content
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LandingPageContent {
+    /// The raw HTML for the landing page.
+    #[serde(default)]
+    pub html: String,
+    /// The JSON structure of the landing page.
+    #[serde(default)]
+    pub json: String,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+///
+/// Tag applied to the contacts that sign up through a landing page
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LandingPageSignupTag {
+    /// The unique id for the tag.
+    #[serde(default)]
+    pub tag_id: u64,
+    /// The name of the tag.
+    #[serde(default)]
+    pub tag_name: String,
+}
+
+///
+/// E-commerce stats of a landing page
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LandingPageEcommerce {
+    /// The total revenue of the landing page.
+    #[serde(default)]
+    pub total_revenue: f32,
+    /// The three-letter ISO 4217 code for the currency.
+    #[serde(default)]
+    pub currency_code: String,
+    /// The total orders of the landing page.
+    #[serde(default)]
+    pub total_orders: u64,
+}
+
+///
+/// Report of a landing page
+///
+/// Endpoints
+///     GET /reporting/landing-pages
+///     GET /reporting/landing-pages/{outreach_id}
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LandingPageReport {
+    /// A string that uniquely identifies this landing page.
+    #[serde(default)]
+    pub id: String,
+    /// The name of this landing page.
+    #[serde(default)]
+    pub name: String,
+    /// The title of this landing page seen in the browser’s title bar.
+    #[serde(default)]
+    pub title: String,
+    /// The url of the published landing page.
+    #[serde(default)]
+    pub url: String,
+    /// The time this landing page was published.
+    #[serde(default)]
+    pub published_at: String,
+    /// The time this landing page was unpublished.
+    #[serde(default)]
+    pub unpublished_at: String,
+    /// The status of the landing page.
+    #[serde(default)]
+    pub status: String,
+    /// The list id connected to this landing page.
+    #[serde(default)]
+    pub list_id: String,
+    /// The name of the list connected to this landing page.
+    #[serde(default)]
+    pub list_name: String,
+    /// The ID used in the Mailchimp web application.
+    #[serde(default)]
+    pub web_id: u64,
+    /// The number of visits to the landing page.
+    #[serde(default)]
+    pub visits: u64,
+    /// The number of unique visits to the landing page.
+    #[serde(default)]
+    pub unique_visits: u64,
+    /// The number of subscribes through the landing page.
+    #[serde(default)]
+    pub subscribes: u64,
+    /// The number of clicks on the landing page.
+    #[serde(default)]
+    pub clicks: u64,
+    /// The percentage of unique visitors that subscribed.
+    #[serde(default)]
+    pub conversion_rate: f32,
+    /// The tags applied to the contacts that sign up.
+    #[serde(default)]
+    pub signup_tags: Vec<LandingPageSignupTag>,
+    /// E-commerce stats of the landing page.
+    #[serde(default)]
+    pub ecommerce: LandingPageEcommerce,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+///
+/// Response for endpoint GET /reporting/landing-pages
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionLandingPageReport {
+    /// An array of objects, each representing the report of a landing page.
+    #[serde(default)]
+    pub landing_pages: Vec<LandingPageReport>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<LandingPageReport> for CollectionLandingPageReport {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<LandingPageReport> {
+        self.landing_pages.clone()
+    }
+}
+
+///
+/// LandingPageReportBuilder
+///
+#[derive(Debug)]
+pub struct LandingPageReportBuilder {}
+
+impl BuildIter for LandingPageReportBuilder {
+    type Item = LandingPageReport;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionLandingPageReport;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
 ///
 /// LandingPageBuilder
 ///
 #[derive(Debug)]
 pub struct LandingPageBuilder {
-    // /// Resource Endpoint
-// pub endpoint: String,
+    /// Resource Endpoint
+    pub endpoint: String,
 }
 
 impl BuildIter for LandingPageBuilder {
@@ -127,7 +381,7 @@ impl BuildIter for LandingPageBuilder {
     fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
-        // in_data.set_endpoint(&self.endpoint);
+        in_data.set_endpoint(&self.endpoint);
         in_data
     }
     ///
@@ -142,9 +396,66 @@ impl BuildIter for LandingPageBuilder {
 
 impl LandingPage {
     ///
-    /// Delete a webhook
+    /// Update the landing page
+    ///
+    /// Arguments:
+    ///     param: The fields to update, the type and the template can't be changed
+    ///
+    pub fn update(&self, param: LandingPageParam) -> MailchimpResult<LandingPage> {
+        // PATCH /landing-pages/{page_id}
+        if param.page_type.is_some() || param.template_id.is_some() {
+            return Err(MailchimpErrorType::invalid_request(
+                "the type and the template of a landing page can't be updated",
+            ));
+        }
+        let endpoint = self.get_base_endpoint();
+        let mut page = self
+            ._api
+            .patch::<LandingPage, LandingPageParam>(&endpoint, param)?;
+        page.set_api(self._api.clone());
+        page.set_endpoint(&self._endpoint);
+        Ok(page)
+    }
+
+    ///
+    /// Publish the landing page
+    ///
+    pub fn publish(&self) -> Option<MailchimpErrorType> {
+        // POST /landing-pages/{page_id}/actions/publish
+        self.action("publish")
+    }
+
+    ///
+    /// Unpublish the landing page
+    ///
+    pub fn unpublish(&self) -> Option<MailchimpErrorType> {
+        // POST /landing-pages/{page_id}/actions/unpublish
+        self.action("unpublish")
+    }
+
+    ///
+    /// Get the HTML and the JSON content of the landing page
+    ///
+    pub fn get_content(&self) -> MailchimpResult<LandingPageContent> {
+        // GET /landing-pages/{page_id}/content
+        let endpoint = self.get_base_endpoint() + "/content";
+        self._api
+            .get::<LandingPageContent>(&endpoint, HashMap::new())
+    }
+
+    fn action(&self, action: &str) -> Option<MailchimpErrorType> {
+        let endpoint = self.get_base_endpoint() + "/actions/" + action;
+        match self
+            ._api
+            .post::<EmptyType, HashMap<String, String>>(&endpoint, HashMap::new())
+        {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
     ///
-    /// Delete a specific webhook in a list.
+    /// Delete a landing page
     ///
     pub fn delete(&self) -> Option<MailchimpErrorType> {
         // DELETE /landing-pages/{page_id}
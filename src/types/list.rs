@@ -1,5 +1,7 @@
+use super::analytics::{list_growth, MonthlyGrowth};
 use super::contact::ContactType;
 use super::empty::EmptyType;
+use super::fields::{FieldProjection, FieldSelector};
 use super::link::LinkType;
 
 use super::list_abuse_report::{
@@ -13,12 +15,14 @@ use super::list_growth_history::{
     ListGrowthHistoryType,
 };
 use super::list_interest_categories::{
-    CollectionListInterestCategories, InterestCategoryParam, ListInterestCategory,
-    ListInterestCategoryBuilder, ListInterestCategoryFilter,
+    find_list_interest, CollectionListInterestCategories, InterestCategoryParam,
+    ListInterestCategory, ListInterestCategoryBuilder, ListInterestCategoryFilter,
 };
+use super::list_interests::ListInterest;
 use super::list_locations::{CollectionListLocations, ListLocationsBuilder};
 use super::list_members::{
-    CollectionListMembers, ListMember, ListMemberParams, ListMembersBuilder, ListMembersFilter,
+    CollectionListMembers, CollectionPartialListMembers, ListMarketingPermision, ListMember,
+    ListMemberParams, ListMembersBuilder, ListMembersFilter, PartialListMembersBuilder,
 };
 use super::list_merge_fields::{
     CollectionListMergeField, ListMergeField, ListMergeFieldBuilder, ListMergeFieldFilter,
@@ -26,19 +30,31 @@ use super::list_merge_fields::{
 };
 use super::list_segments::{
     CollectionListSegment, ListSegment, ListSegmentBuilder, ListSegmentFilter,
+    ModifierListSegmentParams, MAX_STATIC_SEGMENT_BATCH,
 };
 use super::list_signup_forms::{CollectionListSignupForm, ListSignupForm, ListSignupFormBuilder};
+use super::list_tags::{BulkStaticSegmentResult, CollectionListTags};
 use super::list_webhooks::{
     CollectionListWebhooks, ListWebhooks, ListWebhooksBuilder, ListWebhooksParam,
 };
+use super::marketing_permissions::{find_marketing_permission, ConsentRecord, MarketingPermission};
+use super::merge_renderer::MergeRenderer;
+use super::merge_tags::{lint_merge_tags, MergeTagIssue};
+use super::merge_values::MergeFieldError;
+use super::subscriber_hash::SubscriberHash;
+#[cfg(feature = "chrono")]
+use super::timestamp::parse_timestamp;
 use crate::api::{MailchimpApi, MailchimpApiUpdate};
 use crate::internal::error_type::MailchimpErrorType;
 use crate::internal::request::MailchimpResult;
 use crate::iter::MailchimpCollection;
-use crate::iter::{MalchimpIter, ResourceFilter, SimpleFilter};
+use crate::iter::{fetch_all, MalchimpIter, ResourceFilter, SimpleFilter};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use log::error;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::TryInto;
 use std::rc::Rc;
 
 ///
@@ -291,7 +307,88 @@ impl Default for ListsType {
     }
 }
 
+impl FieldProjection for ListType {
+    fn collection_key() -> &'static str {
+        "lists"
+    }
+}
+
+impl FieldSelector<ListType> {
+    /// A string that uniquely identifies this list.
+    pub fn id(self) -> Self {
+        self.with("id")
+    }
+    /// The ID used in the Mailchimp web application.
+    pub fn web_id(self) -> Self {
+        self.with("web_id")
+    }
+    /// The name of the list.
+    pub fn name(self) -> Self {
+        self.with("name")
+    }
+    /// Contact information displayed in campaign footers.
+    pub fn contact(self) -> Self {
+        self.with("contact")
+    }
+    /// Default values for campaigns created for this list.
+    pub fn campaign_defaults(self) -> Self {
+        self.with("campaign_defaults")
+    }
+    /// The date and time that this list was created.
+    pub fn date_created(self) -> Self {
+        self.with("date_created")
+    }
+    /// An auto-generated activity score for the list (0-5).
+    pub fn list_rating(self) -> Self {
+        self.with("list_rating")
+    }
+    /// Our EepURL shortened version of this list’s subscribe form.
+    pub fn subscribe_url_short(self) -> Self {
+        self.with("subscribe_url_short")
+    }
+    /// Whether or not to require the subscriber to confirm subscription via email.
+    pub fn double_optin(self) -> Self {
+        self.with("double_optin")
+    }
+    /// Whether or not the list has marketing permissions (eg. GDPR) enabled.
+    pub fn marketing_permissions(self) -> Self {
+        self.with("marketing_permissions")
+    }
+    /// Stats for the list.
+    pub fn stats(self) -> Self {
+        self.with("stats")
+    }
+    /// The number of active members in the list.
+    pub fn stats_member_count(self) -> Self {
+        self.with("stats.member_count")
+    }
+    /// The average open rate for campaigns sent to this list.
+    pub fn stats_open_rate(self) -> Self {
+        self.with("stats.open_rate")
+    }
+    /// The average click rate for campaigns sent to this list.
+    pub fn stats_click_rate(self) -> Self {
+        self.with("stats.click_rate")
+    }
+}
+
 impl ListType {
+    ///
+    /// `date_created` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn date_created_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_created.as_deref().and_then(parse_timestamp)
+    }
+
+    ///
+    /// Typed selection of fields for the lists endpoints. The resource already
+    /// uses optional fields, so it can be used as the partial response type.
+    ///
+    pub fn fields() -> FieldSelector<ListType> {
+        FieldSelector::new()
+    }
+
     ///
     /// Batch subscribe or unsubscribe list members.
     ///
@@ -403,6 +500,22 @@ impl ListType {
         }
     }
 
+    ///
+    /// Every merge field of the list. Unlike `get_merge_fields`, a failed
+    /// request is returned instead of ending the merge fields early.
+    ///
+    pub(crate) fn fetch_all_merge_fields(&self) -> MailchimpResult<Vec<ListMergeField>> {
+        let endpoint = self.get_base_endpoint() + "/merge-fields";
+        fetch_all(
+            &ListMergeFieldBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            ListMergeFieldFilter::default(),
+        )
+    }
+
     ///
     /// Get information about a specific merge field in a list.
     ///
@@ -722,6 +835,21 @@ impl ListType {
             .get::<ListAbuseReportType>(&endpoint, HashMap::new())
     }
 
+    ///
+    /// Month-over-month growth and churn of the list, see `list_growth`. Fails
+    /// when any page of the growth history can not be read.
+    ///
+    pub fn monthly_growth(&self) -> MailchimpResult<Vec<MonthlyGrowth>> {
+        let endpoint = self.get_base_endpoint() + "/growth-history";
+        let history = fetch_all(
+            &ListGrowthHistoryBuilder {},
+            self._api.clone(),
+            &endpoint,
+            ListGrowthHistoryFilter::default(),
+        )?;
+        Ok(list_growth(&history))
+    }
+
     ///
     /// Get a month-by-month summary of a specific list’s growth activity.
     ///
@@ -849,19 +977,237 @@ impl ListType {
         }
     }
 
+    ///
+    /// Get only the selected fields of the members in a list. Useful to cut the
+    /// payload size when iterating over large lists.
+    ///
+    /// Arguments:
+    ///     fields: Fields to return, e.g. `ListMember::fields().email_address()`
+    ///     filter: Params to filter the response
+    ///
+    pub fn get_members_with_fields(
+        &self,
+        fields: &FieldSelector<ListMember>,
+        filter: Option<ListMembersFilter>,
+    ) -> MalchimpIter<PartialListMembersBuilder> {
+        // GET /lists/{list_id}/members?fields=members.x,total_items
+        let endpoint = self.get_base_endpoint() + "/members";
+        let filter_params = if let Some(f) = filter {
+            f
+        } else {
+            ListMembersFilter::default()
+        }
+        .select(fields);
+
+        match self
+            ._api
+            .get::<CollectionPartialListMembers>(&endpoint, filter_params.build_payload())
+        {
+            Ok(collection) => MalchimpIter {
+                builder: PartialListMembersBuilder {},
+                data: collection.members,
+                cur_filters: filter_params.clone(),
+                cur_it: 0,
+                total_items: collection.total_items,
+                api: self._api.clone(),
+                endpoint: endpoint.clone(),
+            },
+            Err(e) => {
+                error!( target: "mailchimp",  "Get List Members With Fields: Response Error details: {:?}", e);
+                MalchimpIter {
+                    builder: PartialListMembersBuilder {},
+                    data: Vec::new(),
+                    cur_filters: filter_params.clone(),
+                    cur_it: 0,
+                    total_items: 0,
+                    api: self._api.clone(),
+                    endpoint: endpoint.clone(),
+                }
+            }
+        }
+    }
+
     ///
     /// Get information about a specific list member, including a currently subscribed,
     /// unsubscribed, or bounced member.
     ///
     /// Arguments:
-    ///     subscriber_hash: The MD5 hash of the lowercase version of the list member’s email address.
-    ///
-    pub fn get_member_info<'a>(&self, subscriber_hash: &'a str) -> MailchimpResult<ListMember> {
+    ///     member: The email address of the member or the MD5 hash of the lowercase
+    ///         version of the list member’s email address, see `SubscriberHash`.
+    ///
+    pub fn get_member_info<H>(&self, member: H) -> MailchimpResult<ListMember>
+    where
+        H: TryInto<SubscriberHash>,
+        MailchimpErrorType: From<H::Error>,
+    {
         // GET /lists/{list_id}/members/{subscriber_hash}
         let mut endpoint = self.get_base_endpoint() + "/members/";
-        endpoint.push_str(subscriber_hash);
+        endpoint.push_str(member.try_into()?.as_str());
+
+        let mut member = self._api.get::<ListMember>(&endpoint, HashMap::new())?;
+        member.set_api(self._api.clone());
+        member.set_endpoint(&(self.get_base_endpoint() + "/members"));
+        Ok(member)
+    }
+
+    ///
+    /// Get the marketing permissions (GDPR) defined on the list. They are read
+    /// from a member of the list, so the result is empty for an empty list.
+    ///
+    pub fn get_marketing_permissions(&self) -> MailchimpResult<Vec<MarketingPermission>> {
+        // GET /lists/{list_id}/members?count=1&fields=members.marketing_permissions
+        let endpoint = self.get_base_endpoint() + "/members";
+        let filter = ListMembersFilter {
+            count: Some(1),
+            ..ListMembersFilter::default()
+        }
+        .select(&ListMember::fields().marketing_permissions());
+        let collection = self
+            ._api
+            .get::<CollectionPartialListMembers>(&endpoint, filter.build_payload())?;
+        Ok(collection
+            .members
+            .iter()
+            .flat_map(|m| m.marketing_permissions.iter().flatten())
+            .map(MarketingPermission::from)
+            .fold(Vec::new(), |mut acc, p| {
+                if !acc.contains(&p) {
+                    acc.push(p);
+                }
+                acc
+            }))
+    }
+
+    ///
+    /// Opt several members in or out of a marketing permission. Returns the
+    /// emails that could not be updated and the reason.
+    ///
+    /// Arguments:
+    ///     emails: Email addresses of the members
+    ///     text: The text (case-insensitive) or the id of the marketing permission
+    ///     enabled: true to opt-in, false to opt-out
+    ///
+    pub fn update_marketing_permission(
+        &self,
+        emails: &[String],
+        text: &str,
+        enabled: bool,
+    ) -> MailchimpResult<Vec<(String, MailchimpErrorType)>> {
+        let permissions: Vec<ListMarketingPermision> = self
+            .get_marketing_permissions()?
+            .into_iter()
+            .map(|p| ListMarketingPermision {
+                marketing_permission_id: p.marketing_permission_id,
+                text: p.text,
+                enabled,
+            })
+            .collect();
+        let permission = match find_marketing_permission(&permissions, text) {
+            Some(p) => p.clone(),
+            None => {
+                return Err(MailchimpErrorType::invalid_request(&format!(
+                    "The marketing permission `{}` doesn't exist in the list",
+                    text
+                )))
+            }
+        };
+
+        let mut failed = Vec::new();
+        for email in emails {
+            // PATCH /lists/{list_id}/members/{subscriber_hash}
+            let endpoint = format!(
+                "{}/members/{}",
+                self.get_base_endpoint(),
+                SubscriberHash::from_email(email)
+            );
+            let param = ListMemberParams {
+                marketing_permissions: Some(vec![permission.clone()]),
+                ..ListMemberParams::default()
+            };
+            if let Err(e) = self
+                ._api
+                .patch::<ListMember, ListMemberParams>(&endpoint, param)
+            {
+                failed.push((email.clone(), e));
+            }
+        }
+        Ok(failed)
+    }
 
-        self._api.get::<ListMember>(&endpoint, HashMap::new())
+    ///
+    /// Consent audit: walk all the members of the list and return one record
+    /// per member and marketing permission. Fails when any page of members
+    /// can not be read, an incomplete audit is never returned.
+    ///
+    /// Arguments:
+    ///     filter: Filter of the members, e.g. by status
+    ///
+    pub fn consent_audit(
+        &self,
+        filter: Option<ListMembersFilter>,
+    ) -> MailchimpResult<Vec<ConsentRecord>> {
+        let fields = ListMember::fields()
+            .email_address()
+            .marketing_permissions()
+            .last_changed();
+        let endpoint = self.get_base_endpoint() + "/members";
+        let filter_params = filter.unwrap_or_default().select(&fields);
+        let members = fetch_all(
+            &PartialListMembersBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )?;
+        Ok(members
+            .iter()
+            .flat_map(ConsentRecord::from_member)
+            .collect())
+    }
+
+    ///
+    /// Validate the merge fields of the params against the merge fields of
+    /// this list. Returns an empty vector when the values are valid, and fails
+    /// when the merge fields of the list can not be read.
+    ///
+    /// Arguments:
+    ///     param: Member fields to validate
+    ///
+    pub fn validate_member_params(
+        &self,
+        param: &ListMemberParams,
+    ) -> MailchimpResult<Vec<MergeFieldError>> {
+        let fields = self.fetch_all_merge_fields()?;
+        Ok(param.validate_merge_fields(&fields))
+    }
+
+    ///
+    /// Lint the merge tags of the campaign content against the merge fields
+    /// of this list, see `lint_merge_tags`. Fails when the merge fields can
+    /// not be read, linting against part of them would report false issues.
+    ///
+    /// Arguments:
+    ///     content: HTML or plain-text content of the campaign
+    ///
+    pub fn lint_content(&self, content: &str) -> MailchimpResult<Vec<MergeTagIssue>> {
+        let fields = self.fetch_all_merge_fields()?;
+        Ok(lint_merge_tags(content, &fields))
+    }
+
+    ///
+    /// Renderer of the merge tags for the members of this list, with the list
+    /// tags, the merge fields and the interests of the list, see `MergeRenderer`.
+    /// Fails when the merge fields or the interests can not be read.
+    ///
+    pub fn merge_renderer(&self) -> MailchimpResult<MergeRenderer> {
+        let mut renderer = MergeRenderer::new()
+            .list(self)
+            .merge_fields(self.fetch_all_merge_fields()?);
+        for category in self.fetch_all_interest_categories()? {
+            for interest in category.fetch_all_interests()? {
+                renderer = renderer.interest(&category.title, &interest.name, &interest.id);
+            }
+        }
+        Ok(renderer)
     }
 
     ///
@@ -881,22 +1227,44 @@ impl ListType {
     /// Add or update a list member
     ///
     /// Arguments:
-    ///     subscriber_hash: The MD5 hash of the lowercase version of the list member’s email address.
+    ///     member: The email address of the member or the MD5 hash of the lowercase
+    ///         version of the list member’s email address, see `SubscriberHash`.
     ///     param: Member fields to update o create
     ///
-    pub fn add_update_member<'a>(
+    pub fn add_update_member<H>(
         &self,
-        subscriber_hash: &'a str,
+        member: H,
         param: ListMemberParams,
-    ) -> MailchimpResult<ListMember> {
+    ) -> MailchimpResult<ListMember>
+    where
+        H: TryInto<SubscriberHash>,
+        MailchimpErrorType: From<H::Error>,
+    {
         // PUT /lists/{list_id}/members/{subscriber_hash}
         let mut endpoint = self.get_base_endpoint() + "/members/";
-        endpoint.push_str(subscriber_hash);
+        endpoint.push_str(member.try_into()?.as_str());
 
         self._api
             .put::<ListMember, ListMemberParams>(&endpoint, param)
     }
 
+    ///
+    /// Every interest category of the list, see `fetch_all_merge_fields`.
+    ///
+    pub(crate) fn fetch_all_interest_categories(
+        &self,
+    ) -> MailchimpResult<Vec<ListInterestCategory>> {
+        let endpoint = self.get_base_endpoint() + "/interest-categories";
+        fetch_all(
+            &ListInterestCategoryBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            ListInterestCategoryFilter::default(),
+        )
+    }
+
     ///
     /// Get information about a list’s interest categories.
     ///
@@ -949,6 +1317,17 @@ impl ListType {
         }
     }
 
+    ///
+    /// Find an interest of the list by the title of its category and its name
+    ///
+    /// Arguments:
+    ///     category: Title of the interest category
+    ///     name: Name of the interest
+    ///
+    pub fn find_interest(&self, category: &str, name: &str) -> MailchimpResult<ListInterest> {
+        find_list_interest(self._api.clone(), self.id.as_ref().unwrap(), category, name)
+    }
+
     ///
     /// Get information about a specific interest category
     ///
@@ -1102,11 +1481,11 @@ impl ListType {
     pub fn get_specific_segment<'a>(&self, segment_id: &'a str) -> MailchimpResult<ListSegment> {
         // GET /lists/{list_id}/segments/{segment_id}
         let mut endpoint = self.get_base_endpoint();
-        endpoint.push_str("/segments/");
+        endpoint.push_str("/segments");
 
         match self
             ._api
-            .get::<ListSegment>(&(endpoint.clone() + segment_id), HashMap::new())
+            .get::<ListSegment>(&format!("{}/{}", endpoint, segment_id), HashMap::new())
         {
             Ok(data) => {
                 let mut n_data = data;
@@ -1118,6 +1497,149 @@ impl ListType {
         }
     }
 
+    ///
+    /// Create a new segment in a specific list.
+    ///
+    pub fn create_segment(&self, param: ModifierListSegmentParams) -> MailchimpResult<ListSegment> {
+        // POST /lists/{list_id}/segments
+        let mut endpoint = self.get_base_endpoint();
+        endpoint.push_str("/segments");
+
+        match self
+            ._api
+            .post::<ListSegment, ModifierListSegmentParams>(&endpoint, param)
+        {
+            Ok(data) => {
+                let mut n_data = data;
+                n_data.set_api(self._api.clone());
+                n_data.set_endpoint(&endpoint);
+                Ok(n_data)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    ///
+    /// Search for tags on a list by name. If no name is provided, will return all tags on the list.
+    ///
+    /// Arguments:
+    ///     name: The search query used to filter tags. The search query will be compared to
+    ///         each tag as a prefix, so all tags that have a name starting with this field will be returned.
+    ///
+    pub fn search_tags(&self, name: Option<&str>) -> MailchimpResult<CollectionListTags> {
+        // GET /lists/{list_id}/tag-search
+        let mut endpoint = self.get_base_endpoint();
+        endpoint.push_str("/tag-search");
+        let mut payload = HashMap::new();
+        if let Some(n) = name {
+            payload.insert("name".to_string(), n.to_string());
+        }
+        self._api.get::<CollectionListTags>(&endpoint, payload)
+    }
+
+    ///
+    /// Get the tags of the list, i.e. its static segments
+    ///
+    pub fn get_tags(&self, filters: Option<ListSegmentFilter>) -> MalchimpIter<ListSegmentBuilder> {
+        let filter_params = ListSegmentFilter {
+            s_type: Some("static".to_string()),
+            ..filters.unwrap_or_default()
+        };
+        self.get_segments(Some(filter_params))
+    }
+
+    ///
+    /// Find a tag by its exact name
+    ///
+    /// The tag search compares the name as a prefix and only returns the first
+    /// tags, when it doesn't have all of them the static segments are paged
+    /// through before deciding the tag doesn't exist.
+    ///
+    pub fn find_tag(&self, name: &str) -> MailchimpResult<Option<ListSegment>> {
+        let search = self.search_tags(Some(name))?;
+        if let Some(tag) = search.tags.iter().find(|t| t.name == name) {
+            return self.get_specific_segment(&tag.id.to_string()).map(Some);
+        }
+        if search.tags.len() as u64 >= search.total_items {
+            return Ok(None);
+        }
+
+        // GET /lists/{list_id}/segments
+        let mut endpoint = self.get_base_endpoint();
+        endpoint.push_str("/segments");
+        let mut filter = ListSegmentFilter {
+            s_type: Some("static".to_string()),
+            count: Some(1000),
+            offset: Some(0),
+            ..ListSegmentFilter::default()
+        };
+        loop {
+            let page = self
+                ._api
+                .get::<CollectionListSegment>(&endpoint, filter.build_payload())?;
+            let received = page.segments.len() as u64;
+            if let Some(mut segment) = page.segments.into_iter().find(|s| s.name == name) {
+                segment.set_api(self._api.clone());
+                segment.set_endpoint(&endpoint);
+                return Ok(Some(segment));
+            }
+            let offset = filter.offset.unwrap_or(0) + received;
+            if received == 0 || offset >= page.total_items {
+                return Ok(None);
+            }
+            filter.offset = Some(offset);
+        }
+    }
+
+    ///
+    /// Apply a tag to many members, creating the tag if it doesn't exist
+    ///
+    /// The emails are sent in chunks of 500, see `ListSegment::batch_members_in_chunks`.
+    ///
+    /// Arguments:
+    ///     tag: Name of the tag
+    ///     emails: Email addresses of the members. Emails not present on the list are
+    ///         not tagged, they are reported in `BulkStaticSegmentResult::errors`.
+    ///
+    pub fn tag_members(
+        &self,
+        tag: &str,
+        emails: &[String],
+    ) -> MailchimpResult<BulkStaticSegmentResult> {
+        let segment = match self.find_tag(tag)? {
+            Some(s) => s,
+            None => self.create_segment(ModifierListSegmentParams {
+                name: tag.to_string(),
+                static_segment: Some(Vec::new()),
+                options: None,
+            })?,
+        };
+        Ok(segment.batch_members_in_chunks(emails, &[], MAX_STATIC_SEGMENT_BATCH))
+    }
+
+    ///
+    /// Remove a tag from many members
+    ///
+    /// Arguments:
+    ///     tag: Name of the tag
+    ///     emails: Email addresses of the members
+    ///
+    pub fn untag_members(
+        &self,
+        tag: &str,
+        emails: &[String],
+    ) -> MailchimpResult<BulkStaticSegmentResult> {
+        match self.find_tag(tag)? {
+            Some(segment) => {
+                Ok(segment.batch_members_in_chunks(&[], emails, MAX_STATIC_SEGMENT_BATCH))
+            }
+            None => Err(MailchimpErrorType::invalid_request(&format!(
+                "the tag `{}` doesn't exist",
+                tag
+            ))),
+        }
+    }
+
     ///
     /// Delete a list from your Mailchimp account. If you delete a list,
     /// you’ll lose the list history—including subscriber activity, unsubscribes,
@@ -1,6 +1,10 @@
 use super::link::LinkType;
+#[cfg(feature = "chrono")]
+use super::timestamp::parse_timestamp;
 use crate::api::MailchimpApi;
 use crate::iter::{BuildIter, MailchimpCollection, ResourceFilter};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -25,11 +29,42 @@ pub struct ListGrowthHistoryType {
     /// Newly opted-in members on the list for a specific month.
     #[serde(default)]
     pub optins: u64,
+    /// Total subscribed members on the list at the end of the month.
+    #[serde(default)]
+    pub subscribed: u64,
+    /// Newly unsubscribed members on the list for a specific month.
+    #[serde(default)]
+    pub unsubscribed: u64,
+    /// Newly reconfirmed members on the list for a specific month.
+    #[serde(default)]
+    pub reconfirm: u64,
+    /// Newly cleaned (hard-bounced) members on the list for a specific month.
+    #[serde(default)]
+    pub cleaned: u64,
+    /// Pending members on the list for a specific month.
+    #[serde(default)]
+    pub pending: u64,
+    /// Newly deleted members on the list for a specific month.
+    #[serde(default)]
+    pub deleted: u64,
+    /// Subscribers that have been sent transactional emails via Mandrill.
+    #[serde(default)]
+    pub transactional: u64,
     /// A list of link types and descriptions for the API schema documents.
     #[serde(default)]
     pub _links: Vec<LinkType>,
 }
 
+impl ListGrowthHistoryType {
+    ///
+    /// `month` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn month_utc(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.month)
+    }
+}
+
 ///
 /// Get a month-by-month summary of a specific list’s growth activity.
 ///
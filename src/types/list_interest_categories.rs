@@ -6,7 +6,9 @@ use super::list_interests::{
 use crate::api::MailchimpApi;
 use crate::internal::error_type::MailchimpErrorType;
 use crate::internal::request::MailchimpResult;
-use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter, SimpleFilter};
+use crate::iter::{
+    fetch_all, BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter, SimpleFilter,
+};
 use log::error;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -130,6 +132,22 @@ impl ListInterestCategory {
             .patch::<ListInterestCategory, InterestCategoryParam>(&endpoint, param)
     }
 
+    ///
+    /// Every interest of the category. Unlike `get_interests`, a failed
+    /// request is returned instead of ending the interests early.
+    ///
+    pub(crate) fn fetch_all_interests(&self) -> MailchimpResult<Vec<ListInterest>> {
+        let endpoint = self.get_base_endpoint() + "/interests";
+        fetch_all(
+            &ListInterestBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            SimpleFilter::default(),
+        )
+    }
+
     ///
     /// Get a list of this category’s interests.
     ///
@@ -179,6 +197,19 @@ impl ListInterestCategory {
         }
     }
 
+    ///
+    /// Find an interest of this category by its name (case insensitive)
+    ///
+    /// Argument:
+    ///     name: The name of the interest or ‘group name’
+    ///
+    pub fn find_interest(&self, name: &str) -> MailchimpResult<Option<ListInterest>> {
+        Ok(self
+            .fetch_all_interests()?
+            .into_iter()
+            .find(|i| i.name.trim().eq_ignore_ascii_case(name.trim())))
+    }
+
     ///
     /// Create a new interest in a specific category
     ///
@@ -224,6 +255,43 @@ impl ListInterestCategory {
     }
 }
 
+///
+/// Resolve an interest of a list by the title of its category and its name
+///
+pub(crate) fn find_list_interest(
+    api: Rc<MailchimpApi>,
+    list_id: &str,
+    category_title: &str,
+    name: &str,
+) -> MailchimpResult<ListInterest> {
+    // GET /lists/{list_id}/interest-categories
+    let endpoint = format!("lists/{}/interest-categories", list_id);
+    let filter = ListInterestCategoryFilter {
+        count: Some(1000),
+        ..ListInterestCategoryFilter::default()
+    };
+    let collection =
+        api.get::<CollectionListInterestCategories>(&endpoint, filter.build_payload())?;
+
+    for mut category in collection.categories {
+        if category
+            .title
+            .trim()
+            .eq_ignore_ascii_case(category_title.trim())
+        {
+            category.set_api(api.clone());
+            category.set_endpoint(&endpoint);
+            if let Some(interest) = category.find_interest(name)? {
+                return Ok(interest);
+            }
+        }
+    }
+    Err(MailchimpErrorType::invalid_request(&format!(
+        "Interest '{}' not found in the category '{}'",
+        name, category_title
+    )))
+}
+
 ///
 /// Response for endpoint
 ///
@@ -0,0 +1,510 @@
+use super::link::LinkType;
+#[cfg(feature = "chrono")]
+use super::timestamp::parse_timestamp;
+use crate::api::MailchimpApi;
+use crate::iter::{BuildIter, MailchimpCollection, ResourceFilter};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+///
+/// Activity about a campaign sent or opened by the member
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeedCampaignActivity {
+    /// The unique id for the campaign.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The title of the campaign.
+    #[serde(default)]
+    pub campaign_title: String,
+    /// The date and time of the activity in ISO 8601 format.
+    #[serde(default)]
+    pub created_at_timestamp: String,
+    /// Any other data of the activity.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+///
+/// Click on a link of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeedClickActivity {
+    /// The unique id for the campaign.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The title of the campaign.
+    #[serde(default)]
+    pub campaign_title: String,
+    /// The URL the member clicked on.
+    #[serde(default)]
+    pub link_clicked: String,
+    /// The date and time of the activity in ISO 8601 format.
+    #[serde(default)]
+    pub created_at_timestamp: String,
+    /// Any other data of the activity.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+///
+/// Bounce of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeedBounceActivity {
+    /// The unique id for the campaign.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The title of the campaign.
+    #[serde(default)]
+    pub campaign_title: String,
+    /// The type of bounce: hard or soft.
+    #[serde(default)]
+    pub bounce_type: String,
+    /// The date and time of the activity in ISO 8601 format.
+    #[serde(default)]
+    pub created_at_timestamp: String,
+    /// Any other data of the activity.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+///
+/// Unsubscribe of the member
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeedUnsubActivity {
+    /// The unique id for the campaign, if the member unsubscribed from one.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The title of the campaign.
+    #[serde(default)]
+    pub campaign_title: String,
+    /// The reason given by the member.
+    #[serde(default)]
+    pub unsubscribe_reason: String,
+    /// The date and time of the activity in ISO 8601 format.
+    #[serde(default)]
+    pub created_at_timestamp: String,
+    /// Any other data of the activity.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+///
+/// Message of a conversation with the member
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeedConversationActivity {
+    /// The unique id for the campaign of the conversation.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The title of the campaign.
+    #[serde(default)]
+    pub campaign_title: String,
+    /// The content of the message.
+    #[serde(default)]
+    pub message: String,
+    /// The date and time of the activity in ISO 8601 format.
+    #[serde(default)]
+    pub created_at_timestamp: String,
+    /// Any other data of the activity.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+///
+/// Note added to the member
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeedNoteActivity {
+    /// The note id.
+    #[serde(default)]
+    pub note_id: u64,
+    /// The content of the note.
+    #[serde(default)]
+    pub note_text: String,
+    /// The user who created the note.
+    #[serde(default)]
+    pub created_by: String,
+    /// The date and time of the activity in ISO 8601 format.
+    #[serde(default)]
+    pub created_at_timestamp: String,
+    /// Any other data of the activity.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+///
+/// Change in a marketing permission of the member
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeedMarketingPermissionActivity {
+    /// The id for the marketing permission on the list.
+    #[serde(default)]
+    pub marketing_permission_id: String,
+    /// The text of the marketing permission.
+    #[serde(default)]
+    pub marketing_permission_text: String,
+    /// Whether the member opted in or out.
+    #[serde(default)]
+    pub opted_in: bool,
+    /// The date and time of the activity in ISO 8601 format.
+    #[serde(default)]
+    pub created_at_timestamp: String,
+    /// Any other data of the activity.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+///
+/// Ecommerce signup or order of the member
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeedEcommerceActivity {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// The order id, only for orders.
+    #[serde(default)]
+    pub order_id: String,
+    /// The date and time of the activity in ISO 8601 format.
+    #[serde(default)]
+    pub created_at_timestamp: String,
+    /// Any other data of the activity.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+///
+/// Custom event of the member, see `ListMember::create_event`
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeedEventActivity {
+    /// The name of the event.
+    #[serde(default)]
+    pub event_name: String,
+    /// The properties of the event.
+    #[serde(default)]
+    pub event_properties: HashMap<String, Value>,
+    /// The date and time of the activity in ISO 8601 format.
+    #[serde(default)]
+    pub created_at_timestamp: String,
+    /// Any other data of the activity.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+///
+/// Response of the member to a survey
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeedSurveyActivity {
+    /// The survey id.
+    #[serde(default)]
+    pub survey_id: String,
+    /// The title of the survey.
+    #[serde(default)]
+    pub survey_title: String,
+    /// The date and time of the activity in ISO 8601 format.
+    #[serde(default)]
+    pub created_at_timestamp: String,
+    /// Any other data of the activity.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+///
+/// Member Activity Feed item
+///
+/// The variant is chosen by the `activity_type` of the item. Activity types
+/// the library doesn't know are kept as they are in `Other`.
+///
+#[derive(Debug, Clone)]
+pub enum MemberActivityFeedItem {
+    /// The member opened a campaign
+    Open(FeedCampaignActivity),
+    /// The member clicked a link of a campaign
+    Click(FeedClickActivity),
+    /// A campaign bounced
+    Bounce(FeedBounceActivity),
+    /// The member unsubscribed
+    Unsub(FeedUnsubActivity),
+    /// A campaign was sent to the member
+    Sent(FeedCampaignActivity),
+    /// Conversation message
+    Conversation(FeedConversationActivity),
+    /// Note added to the member
+    Note(FeedNoteActivity),
+    /// Marketing permission change
+    MarketingPermission(FeedMarketingPermissionActivity),
+    /// Ecommerce signup
+    EcommerceSignup(FeedEcommerceActivity),
+    /// Ecommerce order
+    EcommerceOrder(FeedEcommerceActivity),
+    /// Custom event
+    Event(FeedEventActivity),
+    /// Survey response
+    SurveyResponse(FeedSurveyActivity),
+    /// Activity type not known by the library, with all its data
+    Other(Value),
+}
+
+impl MemberActivityFeedItem {
+    ///
+    /// Value of the `activity_type` field
+    ///
+    pub fn activity_type(&self) -> &str {
+        match self {
+            MemberActivityFeedItem::Open(_) => "open",
+            MemberActivityFeedItem::Click(_) => "click",
+            MemberActivityFeedItem::Bounce(_) => "bounce",
+            MemberActivityFeedItem::Unsub(_) => "unsub",
+            MemberActivityFeedItem::Sent(_) => "sent",
+            MemberActivityFeedItem::Conversation(_) => "conversation",
+            MemberActivityFeedItem::Note(_) => "note",
+            MemberActivityFeedItem::MarketingPermission(_) => "marketing_permission",
+            MemberActivityFeedItem::EcommerceSignup(_) => "ecommerce_signup",
+            MemberActivityFeedItem::EcommerceOrder(_) => "ecommerce_order",
+            MemberActivityFeedItem::Event(_) => "event",
+            MemberActivityFeedItem::SurveyResponse(_) => "survey_response",
+            MemberActivityFeedItem::Other(v) => v
+                .get("activity_type")
+                .and_then(|t| t.as_str())
+                .unwrap_or_default(),
+        }
+    }
+
+    ///
+    /// Date and time of the activity
+    ///
+    pub fn created_at(&self) -> Option<&String> {
+        match self {
+            MemberActivityFeedItem::Open(a) | MemberActivityFeedItem::Sent(a) => {
+                Some(&a.created_at_timestamp)
+            }
+            MemberActivityFeedItem::Click(a) => Some(&a.created_at_timestamp),
+            MemberActivityFeedItem::Bounce(a) => Some(&a.created_at_timestamp),
+            MemberActivityFeedItem::Unsub(a) => Some(&a.created_at_timestamp),
+            MemberActivityFeedItem::Conversation(a) => Some(&a.created_at_timestamp),
+            MemberActivityFeedItem::Note(a) => Some(&a.created_at_timestamp),
+            MemberActivityFeedItem::MarketingPermission(a) => Some(&a.created_at_timestamp),
+            MemberActivityFeedItem::EcommerceSignup(a)
+            | MemberActivityFeedItem::EcommerceOrder(a) => Some(&a.created_at_timestamp),
+            MemberActivityFeedItem::Event(a) => Some(&a.created_at_timestamp),
+            MemberActivityFeedItem::SurveyResponse(a) => Some(&a.created_at_timestamp),
+            MemberActivityFeedItem::Other(v) => match v.get("created_at_timestamp") {
+                Some(Value::String(t)) => Some(t),
+                _ => None,
+            },
+        }
+    }
+
+    ///
+    /// Date and time of the activity as `DateTime<Utc>`, `None` when it's
+    /// missing or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn created_at_utc(&self) -> Option<DateTime<Utc>> {
+        self.created_at().and_then(|t| parse_timestamp(t))
+    }
+}
+
+impl Serialize for MemberActivityFeedItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde_json::to_value as v;
+        use MemberActivityFeedItem::*;
+        let value = match self {
+            Open(a) | Sent(a) => v(a),
+            Click(a) => v(a),
+            Bounce(a) => v(a),
+            Unsub(a) => v(a),
+            Conversation(a) => v(a),
+            Note(a) => v(a),
+            MarketingPermission(a) => v(a),
+            EcommerceSignup(a) | EcommerceOrder(a) => v(a),
+            Event(a) => v(a),
+            SurveyResponse(a) => v(a),
+            Other(value) => return value.serialize(serializer),
+        };
+        let mut value = value.map_err(S::Error::custom)?;
+        if let Value::Object(map) = &mut value {
+            map.insert(
+                "activity_type".to_string(),
+                Value::from(self.activity_type()),
+            );
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberActivityFeedItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde_json::from_value as v;
+        use MemberActivityFeedItem::*;
+        let value = Value::deserialize(deserializer)?;
+        let activity_type = value
+            .get("activity_type")
+            .and_then(|t| t.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let item = match activity_type.as_str() {
+            "open" => v(value).map(Open),
+            "click" => v(value).map(Click),
+            "bounce" => v(value).map(Bounce),
+            "unsub" => v(value).map(Unsub),
+            "sent" => v(value).map(Sent),
+            "conversation" => v(value).map(Conversation),
+            "note" => v(value).map(Note),
+            "marketing_permission" => v(value).map(MarketingPermission),
+            "ecommerce_signup" => v(value).map(EcommerceSignup),
+            "ecommerce_order" => v(value).map(EcommerceOrder),
+            "event" => v(value).map(Event),
+            "survey_response" => v(value).map(SurveyResponse),
+            _ => Ok(Other(value)),
+        };
+        item.map_err(D::Error::custom)
+    }
+}
+
+///
+/// Response for endpoint  GET /lists/{list_id}/members/{subscriber_hash}/activity-feed
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionMemberActivityFeed {
+    /// An array of objects, each representing a member activity.
+    #[serde(default)]
+    pub activity: Vec<MemberActivityFeedItem>,
+    /// The unique id for the list.
+    #[serde(default)]
+    pub list_id: String,
+    /// The MD5 hash of the lowercase version of the list member’s email address.
+    #[serde(default)]
+    pub email_id: String,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<MemberActivityFeedItem> for CollectionMemberActivityFeed {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+
+    /// Data
+    fn get_values(&self) -> Vec<MemberActivityFeedItem> {
+        self.activity.clone()
+    }
+}
+
+// ================================= ITER =====================
+
+///
+/// Filter for the member activity feed
+///
+#[derive(Debug, Clone)]
+pub struct ActivityFeedFilter {
+    /// A comma-separated list of fields to return. Reference
+    /// parameters of sub-objects with dot notation.
+    pub fields: Option<String>,
+    /// A comma-separated list of fields to exclude. Reference
+    /// parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<String>,
+    /// The number of records to return. Default value is 10.
+    pub count: Option<u64>,
+    /// The number of records from a collection to skip. Iterating over
+    /// large collections with this parameter can be slow. Default value is 0..
+    pub offset: Option<u64>,
+    /// A comma-separated list of activity types to return, e.g. `open,click`.
+    pub activity_filters: Option<String>,
+}
+
+impl Default for ActivityFeedFilter {
+    fn default() -> Self {
+        ActivityFeedFilter {
+            fields: None,
+            exclude_fields: None,
+            count: Some(50),
+            offset: Some(0),
+            activity_filters: None,
+        }
+    }
+}
+
+impl ActivityFeedFilter {
+    ///
+    /// Only return the given activity types, e.g. `&["open", "click"]`
+    ///
+    pub fn only(self, activity_types: &[&str]) -> Self {
+        ActivityFeedFilter {
+            activity_filters: Some(activity_types.join(",")),
+            ..self
+        }
+    }
+}
+
+impl ResourceFilter for ActivityFeedFilter {
+    fn build_payload(&self) -> HashMap<String, String> {
+        let mut payload = HashMap::new();
+
+        if let Some(fields) = &self.fields {
+            payload.insert("fields".to_string(), fields.clone());
+        }
+        if let Some(exclude_fields) = &self.exclude_fields {
+            payload.insert("exclude_fields".to_string(), exclude_fields.clone());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(activity_filters) = &self.activity_filters {
+            payload.insert("activity_filters".to_string(), activity_filters.clone());
+        }
+        payload
+    }
+}
+
+///
+/// MemberActivityFeedBuilder
+///
+#[derive(Debug)]
+pub struct MemberActivityFeedBuilder {}
+
+impl BuildIter for MemberActivityFeedBuilder {
+    type Item = MemberActivityFeedItem;
+    type FilterItem = ActivityFeedFilter;
+    type Collection = CollectionMemberActivityFeed;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
@@ -0,0 +1,138 @@
+use super::link::LinkType;
+#[cfg(feature = "chrono")]
+use super::timestamp::parse_timestamp;
+use crate::api::MailchimpApi;
+use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+///
+/// List Member Event
+///
+/// Custom event of a member, used to trigger the Customer Journeys.
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ListMemberEvent {
+    /// The name for this type of event ('purchased', 'visited', etc).
+    #[serde(default)]
+    pub name: String,
+    /// The properties of the event.
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+    /// The date and time the event occurred in ISO 8601 format.
+    #[serde(default)]
+    pub occurred_at: Option<String>,
+}
+
+impl ListMemberEvent {
+    ///
+    /// `occurred_at` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn occurred_at_utc(&self) -> Option<DateTime<Utc>> {
+        self.occurred_at.as_deref().and_then(parse_timestamp)
+    }
+}
+
+///
+/// Params to add an event for a list member
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ListMemberEventParam {
+    /// The name for this type of event ('purchased', 'visited', etc). Must be 2-30
+    /// characters in length and only contain letters, numbers, underscores and dashes.
+    pub name: String,
+    /// The properties of the event.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub properties: HashMap<String, String>,
+    /// Events created with the is_syncing value set to true will not trigger automations.
+    #[serde(default)]
+    pub is_syncing: bool,
+    /// The date and time the event occurred in ISO 8601 format. If omitted,
+    /// the time the event was received is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub occurred_at: Option<String>,
+}
+
+impl ListMemberEventParam {
+    ///
+    /// Check the name of the event, `Err` with the reason when it's not valid
+    ///
+    pub fn validate(&self) -> Result<(), String> {
+        let len = self.name.chars().count();
+        if !(2..=30).contains(&len) {
+            return Err("the event name must be 2-30 characters in length".to_string());
+        }
+        if !self
+            .name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(
+                "the event name can only contain letters, numbers, underscores and dashes"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+///
+/// Response for endpoint  GET /lists/{list_id}/members/{subscriber_hash}/events
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionListMemberEvents {
+    /// An array of objects, each representing an event of the member.
+    #[serde(default)]
+    pub events: Vec<ListMemberEvent>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<ListMemberEvent> for CollectionListMemberEvents {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+
+    /// Data
+    fn get_values(&self) -> Vec<ListMemberEvent> {
+        self.events.clone()
+    }
+}
+
+// ================================= ITER =====================
+
+///
+/// ListMemberEventBuilder
+///
+#[derive(Debug)]
+pub struct ListMemberEventBuilder {}
+
+impl BuildIter for ListMemberEventBuilder {
+    type Item = ListMemberEvent;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionListMemberEvents;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
@@ -1,15 +1,34 @@
 use super::empty::EmptyType;
+use super::fields::{FieldProjection, FieldSelector};
 use super::link::LinkType;
+use super::list_interest_categories::find_list_interest;
 use super::list_member_activity::{CollectionListMemberActivity, ListMemberActivityBuilder};
+use super::list_member_activity_feed::{
+    ActivityFeedFilter, CollectionMemberActivityFeed, MemberActivityFeedBuilder,
+    MemberActivityFeedItem,
+};
+use super::list_member_events::{
+    CollectionListMemberEvents, ListMemberEvent, ListMemberEventBuilder, ListMemberEventParam,
+};
 use super::list_member_goals::{CollectionListMemberGoal, ListMemberGoalBuilder};
 use super::list_member_notes::{CollectionListMemberNote, ListMemberNote, ListMemberNoteBuilder};
 use super::list_member_tags::{
     CollectionListMemberTag, ListMemberTagBuilder, ListMemberTagParam, ListMemberTagType,
 };
+use super::list_merge_fields::ListMergeField;
+use super::marketing_permissions::find_marketing_permission;
+use super::merge_values::{validate_merge_values, MergeFieldError, MergeValue};
+use super::status::MemberStatus;
+#[cfg(feature = "chrono")]
+use super::timestamp::parse_timestamp;
 use crate::api::MailchimpApi;
 use crate::internal::error_type::MailchimpErrorType;
 use crate::internal::request::MailchimpResult;
-use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter, SimpleFilter};
+use crate::iter::{
+    fetch_all, BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter, SimpleFilter,
+};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -78,8 +97,10 @@ impl Default for ListMarketingPermision {
 #[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(default)]
 pub struct MemberTag {
-    id: u64,
-    name: String,
+    /// The tag id, the same as the id of its static segment.
+    pub id: u64,
+    /// The name of the tag.
+    pub name: String,
 }
 
 ///
@@ -164,16 +185,16 @@ pub struct ListMember {
     pub email_type: String,
     /// Subscriber’s current status.
     #[serde(default)]
-    pub status: String,
+    pub status: MemberStatus,
     /// A subscriber’s reason for unsubscribing.
     #[serde(default)]
     pub unsubscribe_reason: String,
     /// An individual merge var and value for a member.
     #[serde(default)]
-    pub merge_fields: HashMap<String, String>,
+    pub merge_fields: HashMap<String, MergeValue>,
     /// The key of this object’s properties is the ID of the interest in question.
-    #[serde(default)]
-    pub interests: HashMap<String, String>,
+    #[serde(default, deserialize_with = "deserialize_interests")]
+    pub interests: HashMap<String, bool>,
     /// Open and click rates for this subscriber.
     #[serde(default)]
     pub stats: SubscriberStats,
@@ -234,7 +255,166 @@ pub struct ListMember {
     _endpoint: String,
 }
 
+impl FieldProjection for ListMember {
+    fn collection_key() -> &'static str {
+        "members"
+    }
+}
+
+impl FieldSelector<ListMember> {
+    /// The MD5 hash of the lowercase version of the list member’s email address.
+    pub fn id(self) -> Self {
+        self.with("id")
+    }
+    /// Email address for a subscriber.
+    pub fn email_address(self) -> Self {
+        self.with("email_address")
+    }
+    /// An identifier for the address across all of Mailchimp.
+    pub fn unique_email_id(self) -> Self {
+        self.with("unique_email_id")
+    }
+    /// Type of email this member asked to get.
+    pub fn email_type(self) -> Self {
+        self.with("email_type")
+    }
+    /// Subscriber’s current status.
+    pub fn status(self) -> Self {
+        self.with("status")
+    }
+    /// A subscriber’s reason for unsubscribing.
+    pub fn unsubscribe_reason(self) -> Self {
+        self.with("unsubscribe_reason")
+    }
+    /// All the merge fields of the member.
+    pub fn merge_fields(self) -> Self {
+        self.with("merge_fields")
+    }
+    /// All the interests of the member.
+    pub fn interests(self) -> Self {
+        self.with("interests")
+    }
+    /// Open and click rates for this subscriber.
+    pub fn stats(self) -> Self {
+        self.with("stats")
+    }
+    /// A subscriber’s average open rate.
+    pub fn stats_avg_open_rate(self) -> Self {
+        self.with("stats.avg_open_rate")
+    }
+    /// A subscriber’s average clickthrough rate.
+    pub fn stats_avg_click_rate(self) -> Self {
+        self.with("stats.avg_click_rate")
+    }
+    /// IP address the subscriber signed up from.
+    pub fn ip_signup(self) -> Self {
+        self.with("ip_signup")
+    }
+    /// The date and time the subscriber signed up for the list.
+    pub fn timestamp_signup(self) -> Self {
+        self.with("timestamp_signup")
+    }
+    /// The IP address the subscriber used to confirm their opt-in status.
+    pub fn ip_opt(self) -> Self {
+        self.with("ip_opt")
+    }
+    /// The date and time the subscribe confirmed their opt-in status.
+    pub fn timestamp_opt(self) -> Self {
+        self.with("timestamp_opt")
+    }
+    /// Star rating for this member.
+    pub fn member_rating(self) -> Self {
+        self.with("member_rating")
+    }
+    /// The date and time the member’s info was last changed.
+    pub fn last_changed(self) -> Self {
+        self.with("last_changed")
+    }
+    /// The subscriber’s language.
+    pub fn language(self) -> Self {
+        self.with("language")
+    }
+    /// VIP status for subscriber.
+    pub fn vip(self) -> Self {
+        self.with("vip")
+    }
+    /// The list member’s email client.
+    pub fn email_client(self) -> Self {
+        self.with("email_client")
+    }
+    /// Subscriber location information.
+    pub fn location(self) -> Self {
+        self.with("location")
+    }
+    /// The unique code for the location country.
+    pub fn location_country_code(self) -> Self {
+        self.with("location.country_code")
+    }
+    /// The timezone for the location.
+    pub fn location_timezone(self) -> Self {
+        self.with("location.timezone")
+    }
+    /// The marketing permissions for the subscriber.
+    pub fn marketing_permissions(self) -> Self {
+        self.with("marketing_permissions")
+    }
+    /// The most recent Note added about this member.
+    pub fn last_note(self) -> Self {
+        self.with("last_note")
+    }
+    /// The number of tags applied to this member.
+    pub fn tags_count(self) -> Self {
+        self.with("tags_count")
+    }
+    /// The tags applied to this member.
+    pub fn tags(self) -> Self {
+        self.with("tags")
+    }
+    /// The list id.
+    pub fn list_id(self) -> Self {
+        self.with("list_id")
+    }
+}
+
 impl ListMember {
+    ///
+    /// `timestamp_signup` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_signup_utc(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.timestamp_signup)
+    }
+
+    ///
+    /// `timestamp_opt` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_opt_utc(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.timestamp_opt)
+    }
+
+    ///
+    /// `last_changed` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn last_changed_utc(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.last_changed)
+    }
+
+    ///
+    /// Typed selection of fields for the list members endpoints
+    ///
+    /// ```
+    ///     use mailchimp::types::ListMember;
+    ///
+    ///     let fields = ListMember::fields().email_address().merge_fields();
+    ///     assert_eq!(fields.build(), "email_address,merge_fields");
+    /// ```
+    ///
+    pub fn fields() -> FieldSelector<ListMember> {
+        FieldSelector::new()
+    }
+
     // ============== Actions ==============
     ///
     /// Permanently delete a list member
@@ -256,6 +436,114 @@ impl ListMember {
         }
     }
 
+    ///
+    /// Update the member and return the updated member. Only the fields with
+    /// a value are changed.
+    ///
+    /// Arguments:
+    ///     param: Member fields to update
+    ///
+    pub fn update(&self, param: ListMemberParams) -> MailchimpResult<ListMember> {
+        // PATCH /lists/{list_id}/members/{subscriber_hash}
+        let endpoint = self.build_list_endpoint();
+        let member = self
+            ._api
+            .patch::<ListMember, ListMemberParams>(&endpoint, param)?;
+        Ok(self.bind(member))
+    }
+
+    ///
+    /// Add or update the member and return it. When the params don't include
+    /// them, the email address of the member is used and `status_if_new`
+    /// defaults to its current status if it's subscribed, unsubscribed or
+    /// pending. For any other status `status_if_new` must be given.
+    ///
+    /// Arguments:
+    ///     param: Member fields to update o create
+    ///
+    pub fn upsert(&self, param: ListMemberParams) -> MailchimpResult<ListMember> {
+        // PUT /lists/{list_id}/members/{subscriber_hash}
+        let status_if_new = match (&param.status_if_new, &self.status) {
+            (Some(MemberStatus::Archived), _) => {
+                return Err(MailchimpErrorType::invalid_request(
+                    "archived is not a valid status_if_new",
+                ))
+            }
+            (Some(status), _) => status.clone(),
+            (None, MemberStatus::Subscribed)
+            | (None, MemberStatus::Unsubscribed)
+            | (None, MemberStatus::Pending) => self.status.clone(),
+            (None, status) => {
+                return Err(MailchimpErrorType::invalid_request(&format!(
+                    "status_if_new is required to upsert a member with status `{}`",
+                    status
+                )))
+            }
+        };
+        let param = ListMemberParams {
+            email_address: param
+                .email_address
+                .or_else(|| Some(self.email_address.clone())),
+            status_if_new: Some(status_if_new),
+            ..param
+        };
+        let endpoint = self.build_list_endpoint();
+        let member = self
+            ._api
+            .put::<ListMember, ListMemberParams>(&endpoint, param)?;
+        Ok(self.bind(member))
+    }
+
+    ///
+    /// Unsubscribe the member and return the updated member
+    ///
+    pub fn unsubscribe(&self) -> MailchimpResult<ListMember> {
+        self.update(ListMemberParams {
+            status: Some(MemberStatus::Unsubscribed),
+            ..ListMemberParams::default()
+        })
+    }
+
+    ///
+    /// Resubscribe the member with double opt-in: the status changes to
+    /// pending and Mailchimp sends the confirmation email. Returns the updated
+    /// member.
+    ///
+    pub fn resubscribe(&self) -> MailchimpResult<ListMember> {
+        self.update(ListMemberParams {
+            status: Some(MemberStatus::Pending),
+            ..ListMemberParams::default()
+        })
+    }
+
+    ///
+    /// Change the email address of the member and return the updated member.
+    /// The subscriber hash (`id`) of the returned member changes too.
+    ///
+    /// Arguments:
+    ///     email_address: New email address
+    ///
+    pub fn change_email(&self, email_address: &str) -> MailchimpResult<ListMember> {
+        if !email_address.contains('@') {
+            return Err(MailchimpErrorType::invalid_request(
+                "The new email address is not valid",
+            ));
+        }
+        self.update(ListMemberParams {
+            email_address: Some(email_address.trim().to_string()),
+            ..ListMemberParams::default()
+        })
+    }
+
+    ///
+    /// Keep the api and endpoint of this member in a member returned by the API
+    ///
+    fn bind(&self, mut member: ListMember) -> ListMember {
+        member.set_api(self._api.clone());
+        member.set_endpoint(&self._endpoint);
+        member
+    }
+
     ///
     /// Delete a member from a list/segment
     ///
@@ -314,7 +602,7 @@ impl ListMember {
     }
 
     fn build_list_endpoint(&self) -> String {
-        format!("/list/{}/members/{}", self.list_id, self.id)
+        format!("lists/{}/members/{}", self.list_id, self.id)
     }
 
     ///
@@ -354,6 +642,53 @@ impl ListMember {
         }
     }
 
+    ///
+    /// Get the activity feed of the member: opens, clicks, bounces, notes,
+    /// events, orders, etc.
+    ///
+    /// Arguments:
+    ///     filters: Use `ActivityFeedFilter::only` to get only some activity types
+    ///
+    pub fn get_activity_feed(
+        &self,
+        filters: Option<ActivityFeedFilter>,
+    ) -> MalchimpIter<MemberActivityFeedBuilder> {
+        // GET /lists/{list_id}/members/{subscriber_hash}/activity-feed
+        let endpoint = self.build_list_endpoint() + "/activity-feed";
+        let filter_params = if let Some(f) = filters {
+            f
+        } else {
+            ActivityFeedFilter::default()
+        };
+
+        match self
+            ._api
+            .get::<CollectionMemberActivityFeed>(&endpoint, filter_params.build_payload())
+        {
+            Ok(collection) => MalchimpIter {
+                builder: MemberActivityFeedBuilder {},
+                data: collection.activity,
+                cur_filters: filter_params.clone(),
+                cur_it: 0,
+                total_items: collection.total_items,
+                api: self._api.clone(),
+                endpoint: endpoint.clone(),
+            },
+            Err(e) => {
+                error!( target: "mailchimp",  "Get Member Activity Feed: Response Error details: {:?}", e);
+                MalchimpIter {
+                    builder: MemberActivityFeedBuilder {},
+                    data: Vec::new(),
+                    cur_filters: filter_params.clone(),
+                    cur_it: 0,
+                    total_items: 0,
+                    api: self._api.clone(),
+                    endpoint: endpoint.clone(),
+                }
+            }
+        }
+    }
+
     ///
     /// Get information about recent goal events for a specific list member.
     ///
@@ -531,6 +866,256 @@ impl ListMember {
         self._api
             .post::<ListMemberNote, HashMap<String, String>>(&endpoint, payload)
     }
+
+    // ============== Events ==============
+    ///
+    /// Add an event for the list member, used to trigger the Customer Journeys
+    ///
+    /// Arguments:
+    ///     name: The name for this type of event ('purchased', 'visited', etc). Must be
+    ///         2-30 characters in length and only contain letters, numbers, underscores and dashes.
+    ///     properties: The properties of the event.
+    ///     occurred_at: The date and time the event occurred, `None` to use the time it is received.
+    ///     is_syncing: Events created with is_syncing set to true will not trigger automations.
+    ///
+    pub fn create_event(
+        &self,
+        name: &str,
+        properties: HashMap<String, String>,
+        occurred_at: Option<String>,
+        is_syncing: bool,
+    ) -> Option<MailchimpErrorType> {
+        // POST /lists/{list_id}/members/{subscriber_hash}/events
+        let param = ListMemberEventParam {
+            name: name.to_string(),
+            properties,
+            is_syncing,
+            occurred_at,
+        };
+        if let Err(reason) = param.validate() {
+            return Some(MailchimpErrorType::invalid_request(&reason));
+        }
+        let endpoint = self.build_list_endpoint() + "/events";
+
+        match self
+            ._api
+            .post::<EmptyType, ListMemberEventParam>(&endpoint, param)
+        {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    ///
+    /// Get events for the list member
+    ///
+    /// Arguments:
+    ///     filters
+    ///
+    pub fn get_events(
+        &self,
+        filters: Option<SimpleFilter>,
+    ) -> MalchimpIter<ListMemberEventBuilder> {
+        // GET /lists/{list_id}/members/{subscriber_hash}/events
+        let endpoint = self.build_list_endpoint() + "/events";
+        let filter_params = if let Some(f) = filters {
+            f
+        } else {
+            SimpleFilter::default()
+        };
+
+        match self
+            ._api
+            .get::<CollectionListMemberEvents>(&endpoint, filter_params.build_payload())
+        {
+            Ok(collection) => MalchimpIter {
+                builder: ListMemberEventBuilder {},
+                data: collection.events,
+                cur_filters: filter_params.clone(),
+                cur_it: 0,
+                total_items: collection.total_items,
+                api: self._api.clone(),
+                endpoint: endpoint.clone(),
+            },
+            Err(e) => {
+                error!( target: "mailchimp",  "Get List Member Events: Response Error details: {:?}", e);
+                MalchimpIter {
+                    builder: ListMemberEventBuilder {},
+                    data: Vec::new(),
+                    cur_filters: filter_params.clone(),
+                    cur_it: 0,
+                    total_items: 0,
+                    api: self._api.clone(),
+                    endpoint: endpoint.clone(),
+                }
+            }
+        }
+    }
+
+    // ============== Complete reads ==============
+    ///
+    /// Every note of the member. Unlike `get_notes`, a failed request is
+    /// returned instead of ending the notes early.
+    ///
+    pub(crate) fn fetch_all_notes(&self) -> MailchimpResult<Vec<ListMemberNote>> {
+        let endpoint = self.get_base_endpoint() + "/notes";
+        let builder = ListMemberNoteBuilder {
+            endpoint: endpoint.clone(),
+        };
+        fetch_all(
+            &builder,
+            self._api.clone(),
+            &endpoint,
+            SimpleFilter::default(),
+        )
+    }
+
+    ///
+    /// Every tag of the member, see `fetch_all_notes`.
+    ///
+    pub(crate) fn fetch_all_tags(&self) -> MailchimpResult<Vec<String>> {
+        let endpoint = self.get_base_endpoint() + "/tags";
+        fetch_all(
+            &ListMemberTagBuilder {},
+            self._api.clone(),
+            &endpoint,
+            SimpleFilter::default(),
+        )
+    }
+
+    ///
+    /// The whole activity feed of the member, see `fetch_all_notes`.
+    ///
+    pub(crate) fn fetch_all_activity_feed(&self) -> MailchimpResult<Vec<MemberActivityFeedItem>> {
+        let endpoint = self.build_list_endpoint() + "/activity-feed";
+        fetch_all(
+            &MemberActivityFeedBuilder {},
+            self._api.clone(),
+            &endpoint,
+            ActivityFeedFilter::default(),
+        )
+    }
+
+    ///
+    /// Every custom event of the member, see `fetch_all_notes`.
+    ///
+    pub(crate) fn fetch_all_events(&self) -> MailchimpResult<Vec<ListMemberEvent>> {
+        let endpoint = self.build_list_endpoint() + "/events";
+        fetch_all(
+            &ListMemberEventBuilder {},
+            self._api.clone(),
+            &endpoint,
+            SimpleFilter::default(),
+        )
+    }
+
+    // ============== Marketing permissions ==============
+    ///
+    /// Opt the member in or out of a marketing permission and return the
+    /// updated member.
+    ///
+    /// Arguments:
+    ///     text: The text (case-insensitive) or the id of the marketing permission
+    ///     enabled: true to opt-in, false to opt-out
+    ///
+    pub fn set_marketing_permission(
+        &self,
+        text: &str,
+        enabled: bool,
+    ) -> MailchimpResult<ListMember> {
+        let permission = match find_marketing_permission(&self.marketing_permissions, text) {
+            Some(p) => ListMarketingPermision {
+                enabled,
+                ..p.clone()
+            },
+            None => {
+                return Err(MailchimpErrorType::invalid_request(&format!(
+                    "The marketing permission `{}` doesn't exist in the list",
+                    text
+                )))
+            }
+        };
+        self.update(ListMemberParams {
+            marketing_permissions: Some(vec![permission]),
+            ..ListMemberParams::default()
+        })
+    }
+
+    ///
+    /// Opt the member in to a marketing permission, see `set_marketing_permission`
+    ///
+    pub fn grant_marketing_permission(&self, text: &str) -> MailchimpResult<ListMember> {
+        self.set_marketing_permission(text, true)
+    }
+
+    ///
+    /// Opt the member out of a marketing permission, see `set_marketing_permission`
+    ///
+    pub fn revoke_marketing_permission(&self, text: &str) -> MailchimpResult<ListMember> {
+        self.set_marketing_permission(text, false)
+    }
+
+    ///
+    /// Return true when the member has opted-in to the marketing permission
+    ///
+    /// Arguments:
+    ///     text: The text (case-insensitive) or the id of the marketing permission
+    ///
+    pub fn has_marketing_permission(&self, text: &str) -> bool {
+        find_marketing_permission(&self.marketing_permissions, text)
+            .map(|p| p.enabled)
+            .unwrap_or(false)
+    }
+
+    // ============== Interests ==============
+    ///
+    /// Return true when the member belongs to the interest
+    ///
+    /// Arguments:
+    ///     interest_id: The id of the interest
+    ///
+    pub fn is_interested(&self, interest_id: &str) -> bool {
+        self.interests.get(interest_id).cloned().unwrap_or(false)
+    }
+
+    ///
+    /// Add or remove the member from several interests at once and return the
+    /// updated member.
+    ///
+    /// Arguments:
+    ///     interests: Interest id and true to add the member, false to remove it
+    ///
+    pub fn update_interests(
+        &self,
+        interests: HashMap<String, bool>,
+    ) -> MailchimpResult<ListMember> {
+        // PATCH /lists/{list_id}/members/{subscriber_hash}
+        self.update(ListMemberParams {
+            interests: Some(interests),
+            ..ListMemberParams::default()
+        })
+    }
+
+    ///
+    /// Add or remove the member from an interest resolved by the title of its
+    /// category and its name, e.g. `set_interest("Favorite color", "Blue", true)`
+    ///
+    /// Arguments:
+    ///     category: Title of the interest category
+    ///     name: Name of the interest
+    ///     subscribed: true to add the member to the interest, false to remove it
+    ///
+    pub fn set_interest(
+        &self,
+        category: &str,
+        name: &str,
+        subscribed: bool,
+    ) -> MailchimpResult<ListMember> {
+        let interest = find_list_interest(self._api.clone(), &self.list_id, category, name)?;
+        let mut interests = HashMap::new();
+        interests.insert(interest.id, subscribed);
+        self.update_interests(interests)
+    }
 }
 
 ///
@@ -594,7 +1179,7 @@ pub struct ListMembersFilter {
     /// The email type.
     pub email_type: Option<String>,
     /// The subscriber’s status.
-    pub status: Option<String>,
+    pub status: Option<MemberStatus>,
     /// Restrict results to subscribers who opted-in after the set timeframe.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
     pub since_timestamp_opt: Option<String>,
@@ -781,6 +1366,33 @@ impl ResourceFilter for ListMembersFilter {
     }
 }
 
+impl ListMembersFilter {
+    ///
+    /// Return only the selected member fields. `total_items` is kept so the
+    /// iteration over the collection still works.
+    ///
+    pub fn select(mut self, fields: &FieldSelector<ListMember>) -> Self {
+        self.fields = if fields.is_empty() {
+            None
+        } else {
+            Some(fields.build_for_collection())
+        };
+        self
+    }
+
+    ///
+    /// Exclude the selected member fields from the response
+    ///
+    pub fn exclude(mut self, fields: &FieldSelector<ListMember>) -> Self {
+        self.exclude_fields = if fields.is_empty() {
+            None
+        } else {
+            Some(fields.build_exclude_for_collection())
+        };
+        self
+    }
+}
+
 ///
 /// List Members Builder
 ///
@@ -817,7 +1429,7 @@ impl BuildIter for ListMembersBuilder {
 ///
 /// ListMemberParams
 ///
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 pub struct ListMemberParams {
     /// Email address for a subscriber.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -831,19 +1443,19 @@ pub struct ListMemberParams {
     ///     * pending
     ///     * transactional
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status_if_new: Option<String>,
+    pub status_if_new: Option<MemberStatus>,
     /// Type of email this member asked to get (‘html’ or ‘text’).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub email_type: Option<String>,
     /// Subscriber’s current status.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MemberStatus>,
     /// An individual merge var and value for a member.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub merge_fields: Option<HashMap<String, String>>,
+    pub merge_fields: Option<HashMap<String, MergeValue>>,
     /// The key of this object’s properties is the ID of the interest in question.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub interests: Option<HashMap<String, String>>,
+    pub interests: Option<HashMap<String, bool>>,
     /// If set/detected, the subscriber’s language.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
@@ -875,3 +1487,232 @@ pub struct ListMemberParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timestamp_opt: Option<String>,
 }
+
+impl ListMemberParams {
+    ///
+    /// Add or remove the member from an interest
+    ///
+    /// Arguments:
+    ///     interest_id: The id of the interest
+    ///     subscribed: true to add the member to the interest, false to remove it
+    ///
+    pub fn set_interest(&mut self, interest_id: &str, subscribed: bool) {
+        self.interests
+            .get_or_insert_with(HashMap::new)
+            .insert(interest_id.to_string(), subscribed);
+    }
+
+    ///
+    /// Validate the merge fields against the definitions of the list before
+    /// sending them. The required fields are only checked when `status_if_new`
+    /// is set, i.e. when the member could be created.
+    ///
+    /// Arguments:
+    ///     fields: Merge fields of the list, see `ListType::get_merge_fields`
+    ///
+    pub fn validate_merge_fields(&self, fields: &[ListMergeField]) -> Vec<MergeFieldError> {
+        let empty = HashMap::new();
+        let values = self.merge_fields.as_ref().unwrap_or(&empty);
+        validate_merge_values(fields, values, self.status_if_new.is_some())
+    }
+}
+
+///
+/// Partial List Member
+///
+/// Response type for member requests made with a field selection
+/// (see `ListMember::fields`). Every field is optional, so the fields that were
+/// not requested are `None` instead of a misleading default value.
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PartialListMember {
+    /// The MD5 hash of the lowercase version of the list member’s email address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Email address for a subscriber.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    /// An identifier for the address across all of Mailchimp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unique_email_id: Option<String>,
+    /// Type of email this member asked to get (‘html’ or ‘text’).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_type: Option<String>,
+    /// Subscriber’s current status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<MemberStatus>,
+    /// A subscriber’s reason for unsubscribing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsubscribe_reason: Option<String>,
+    /// An individual merge var and value for a member.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_fields: Option<HashMap<String, MergeValue>>,
+    /// The key of this object’s properties is the ID of the interest in question.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_interests"
+    )]
+    pub interests: Option<HashMap<String, bool>>,
+    /// Open and click rates for this subscriber.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<SubscriberStats>,
+    /// IP address the subscriber signed up from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_signup: Option<String>,
+    /// The date and time the subscriber signed up for the list in ISO 8601 format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp_signup: Option<String>,
+    /// The IP address the subscriber used to confirm their opt-in status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_opt: Option<String>,
+    /// The date and time the subscribe confirmed their opt-in status in ISO 8601 format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp_opt: Option<String>,
+    /// Star rating for this member, between 1 and 5.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member_rating: Option<u64>,
+    /// The date and time the member’s info was last changed in ISO 8601 format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_changed: Option<String>,
+    /// If set/detected, the subscriber’s language.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// VIP status for subscriber.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vip: Option<bool>,
+    /// The list member’s email client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_client: Option<String>,
+    /// Subscriber location information.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<SubscriberLocation>,
+    /// The marketing permissions for the subscriber.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marketing_permissions: Option<Vec<ListMarketingPermision>>,
+    /// The most recent Note added about this member.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_note: Option<ListNote>,
+    /// The number of tags applied to this member.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags_count: Option<u64>,
+    /// The tags applied to this member.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<MemberTag>>,
+    /// The list id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_id: Option<String>,
+}
+
+impl PartialListMember {
+    ///
+    /// `timestamp_signup` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_signup_utc(&self) -> Option<DateTime<Utc>> {
+        self.timestamp_signup.as_deref().and_then(parse_timestamp)
+    }
+
+    ///
+    /// `timestamp_opt` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_opt_utc(&self) -> Option<DateTime<Utc>> {
+        self.timestamp_opt.as_deref().and_then(parse_timestamp)
+    }
+
+    ///
+    /// `last_changed` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn last_changed_utc(&self) -> Option<DateTime<Utc>> {
+        self.last_changed.as_deref().and_then(parse_timestamp)
+    }
+}
+
+///
+/// Collection of partial list members
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionPartialListMembers {
+    /// An array of objects, each representing a specific list member.
+    #[serde(default)]
+    pub members: Vec<PartialListMember>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+}
+
+impl MailchimpCollection<PartialListMember> for CollectionPartialListMembers {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+
+    /// Data
+    fn get_values(&self) -> Vec<PartialListMember> {
+        self.members.clone()
+    }
+}
+
+///
+/// Partial List Members Builder
+///
+#[derive(Debug)]
+pub struct PartialListMembersBuilder {}
+
+impl BuildIter for PartialListMembersBuilder {
+    type Item = PartialListMember;
+    type FilterItem = ListMembersFilter;
+    type Collection = CollectionPartialListMembers;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// Interests as returned by the API. Old responses send the booleans as
+/// strings ("true"/"false").
+///
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum InterestFlag {
+    Bool(bool),
+    Text(String),
+}
+
+fn deserialize_interests<'de, D>(deserializer: D) -> Result<HashMap<String, bool>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw = Option::<HashMap<String, InterestFlag>>::deserialize(deserializer)?;
+    Ok(raw
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(id, flag)| match flag {
+            InterestFlag::Bool(b) => (id, b),
+            InterestFlag::Text(t) => (id, t.trim().eq_ignore_ascii_case("true")),
+        })
+        .collect())
+}
+
+fn deserialize_optional_interests<'de, D>(
+    deserializer: D,
+) -> Result<Option<HashMap<String, bool>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserialize_interests(deserializer).map(Some)
+}
@@ -1,10 +1,15 @@
-use serde::{Deserialize, Serialize};
+use super::status::string_enum;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
 ///
 /// Segment Conditions
 ///
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Some responses (e.g. the `segment_opts` of old campaigns) omit the
+/// `condition_type`; in that case it is inferred from the field and the op.
+///
+#[derive(Serialize, Debug, Clone)]
 #[serde(tag = "condition_type")]
 pub enum SegmentConditionsType {
     /// Aim Segment
@@ -88,6 +93,7 @@ pub enum SegmentConditionsType {
     /// Predicted Age Segment
     PredictedAge(SegmentConditionInner),
     /// New Subscribers Prebuilt Segment
+    #[serde(rename = "NewSubscribers")]
     NewSubsribers(SegmentConditionInner),
 }
 
@@ -108,7 +114,8 @@ pub struct SegmentConditionInner {
     /// op
     pub op: SegmentConditionOp,
     /// value
-    pub value: Value,
+    #[serde(default)]
+    pub value: SegmentValue,
     /// Extra
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extra: Option<String>,
@@ -122,7 +129,8 @@ pub struct SegmentGeoCondition {
     /// Op
     pub op: SegmentConditionOp,
     /// Value
-    pub value: Value,
+    #[serde(default)]
+    pub value: SegmentValue,
     /// addr
     pub addr: String,
     /// lat
@@ -132,142 +140,502 @@ pub struct SegmentGeoCondition {
 }
 
 ///
-/// Segment Operator
+/// Value of a segment condition
 ///
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(untagged, rename_all = "snake_case")]
-pub enum SegmentConditionOp {
-    // Aim conditions
-    /// The campaign was opened by the subscriber.
-    Open,
-    /// The campaign was clicked by the subscriber.
-    Click,
-    /// The campaign was sent to the subscriber.
-    Sent,
-    /// The campaign was not opened by the subscriber.
-    Noopen,
-    /// The campaign was not clicked by the subscriber.
-    Noclick,
-    /// The campaign was not sent to the subscriber.
-    Nosent,
-
-    // Automation, SurveyMonkey conditions
-    /// The member has started the automation workflow or survey.
-    Started,
-    /// The member has completed the automation workflow or survey.
-    Completed,
-    /// The member has not started the automation workflow or survey.
-    NotStarted,
-    /// The member has not completed the automation workflow or survey.
-    NotCompleted,
-
-    // CampaignPoll, Conversation, SocialNetwork, VIP conditions
-    /// The subscriber is a member.
-    Member,
-    /// The subscriber is not a member.
-    Notmember,
-
-    // Date, etc. conditions
-    /// The field is greater than the value.
-    Greater,
-    /// The field is less than the value.
-    Less,
-    /// The field is exactly the value.
-    Is,
-    /// The field is not equal to the value.
-    Not,
-    /// The field is blank.
-    Blank,
-    /// The field is not blank.
-    BlankNot,
-    /// The field is within the range.
-    Within,
-    /// The field is not within the range.
-    Notwithin,
-
-    // EmailClient conditions
-    /// The email client is the value.
-    ClientIs,
-    /// The email client is not the value.
-    ClientNot,
-
-    // SignupSource conditions
-    /// The signup source is the value.
-    SourceIs,
-    /// The signup source is not the value.
-    SourceNot,
-
-    // EcommCategory, AddressMerge conditions
-    /// The field contains the value.
-    Contains,
-    /// The field does not contain the value.
-    Notcontain,
-    /// The field starts with the value.
-    Starts,
-    /// The field ends with the value.
-    Ends,
-
-    // Goal conditions
-    /// The goal is not the value. (Inverse of `Is` for Goal Segments.)
-    GoalNot,
-    /// The goal does not contain the value. (Inverse of `Contains` for Goal Segments.)
-    GoalNotcontain,
-
-    // FuzzySegment conditions
-    /// The subscriber is in the value's "similar subscribers" segment.
-    FuzzyIs,
-    /// The subscriber is not in the value's "similar subscribers" segment.
-    FuzzyNot,
-
-    // StaticSegment conditions
-    /// The subscriber is has the value's tag.
-    StaticIs,
-    /// The subscriber does not have the value's tag.
-    StaticNot,
-
-    // IPGeoCountryState conditions
-    /// The subscriber is in the value's country code.
-    Ipgeocountry,
-    /// The subscriber is not in the value's country code.
-    Ipgeonotcountry,
-    /// The subscriber is in the value's state code.
-    Ipgeostate,
-    /// The subscriber is not in the value's state code.
-    Ipgeonotstate,
-
-    // IPGeoIn conditions
-    /// The subscriber is in a radius of {value} around {addr} or the {lat}/{lng} coordinates.
-    Ipgeoin,
-    /// The subscriber is not in a radius of {value} around {addr} or the {lat}/{lng} coordinates.
-    Ipgeonotin,
-
-    // IPGeoInZip condition
-    /// The subscriber is in a radius of {value} around the ZIP code, {extra}.
-    Ipgeoinzip,
-
-    // IPGeoUnknown condition
-    /// The subscriber's location is unknown.
-    Ipgeounknown,
-
-    // IPGeoZip conditions
-    /// The subscriber's ZIP code is the value.
-    Ipgeoiszip,
-    /// The subscriber's ZIP code is not the value.
-    Ipgeonotzip,
-
-    // SocialNetworkFollow conditions
-    /// The subscriber follows you on social media.
-    Follow,
-    /// The subscriber does not follow you on social media.
-    Notfollow,
-
-    // ZipMerge condition
-    /// The subscriber's address is {value} distance from the {extra} city or ZIP code.
-    Geoin,
-
-    // NewSubscribers condition
-    /// The subscriber joined within a given time period.
-    DateWithin,
+/// Depending on the condition it is a number (member rating, days, static
+/// segment id), a text (merge field value, language) or a list of ids
+/// (interests).
+///
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum SegmentValue {
+    /// Integer value
+    Integer(i64),
+    /// Decimal value
+    Float(f64),
+    /// Text value
+    Text(String),
+    /// List of values
+    List(Vec<String>),
+}
+
+impl SegmentValue {
+    ///
+    /// Return the value as text, `None` for lists
+    ///
+    pub fn as_text(&self) -> Option<String> {
+        match self {
+            SegmentValue::Integer(v) => Some(v.to_string()),
+            SegmentValue::Float(v) => Some(v.to_string()),
+            SegmentValue::Text(v) => Some(v.clone()),
+            SegmentValue::List(_) => None,
+        }
+    }
+
+    ///
+    /// Return the numeric value. Numeric text is converted too.
+    ///
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            SegmentValue::Integer(v) => Some(*v as f64),
+            SegmentValue::Float(v) => Some(*v),
+            SegmentValue::Text(v) => v.trim().parse::<f64>().ok(),
+            SegmentValue::List(_) => None,
+        }
+    }
+
+    ///
+    /// Return the values of a list. Any other value is returned as a list of one.
+    ///
+    pub fn as_list(&self) -> Vec<String> {
+        match self {
+            SegmentValue::List(v) => v.clone(),
+            _ => self.as_text().into_iter().collect(),
+        }
+    }
+}
+
+impl Default for SegmentValue {
+    fn default() -> Self {
+        SegmentValue::Text(String::new())
+    }
+}
+
+impl<'a> From<&'a str> for SegmentValue {
+    fn from(value: &'a str) -> Self {
+        SegmentValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for SegmentValue {
+    fn from(value: String) -> Self {
+        SegmentValue::Text(value)
+    }
+}
+
+impl From<i64> for SegmentValue {
+    fn from(value: i64) -> Self {
+        SegmentValue::Integer(value)
+    }
+}
+
+impl From<u64> for SegmentValue {
+    fn from(value: u64) -> Self {
+        SegmentValue::Integer(value as i64)
+    }
+}
+
+impl From<f64> for SegmentValue {
+    fn from(value: f64) -> Self {
+        SegmentValue::Float(value)
+    }
+}
+
+impl From<Vec<String>> for SegmentValue {
+    fn from(value: Vec<String>) -> Self {
+        SegmentValue::List(value)
+    }
+}
+
+impl SegmentConditionsType {
+    ///
+    /// Value of the `condition_type` field
+    ///
+    pub fn condition_type(&self) -> &'static str {
+        use SegmentConditionsType::*;
+        match self {
+            Aim(_) => "Aim",
+            Automation(_) => "Automation",
+            CampaignPoll(_) => "CampaignPoll",
+            Conversation(_) => "Conversation",
+            Date(_) => "Date",
+            EmailClient(_) => "EmailClient",
+            Language(_) => "Language",
+            MemberRating(_) => "MemberRating",
+            SignupSource(_) => "SignupSource",
+            SurveyMonkey(_) => "SurveyMonkey",
+            VIP(_) => "VIP",
+            Interests(_) => "Interests",
+            EcommCategory(_) => "EcommCategory",
+            EcommNumber(_) => "EcommNumber",
+            EcommPurchased(_) => "EcommPurchased",
+            EcommSpent(_) => "EcommSpent",
+            EcommStore(_) => "EcommStore",
+            GoalActivity(_) => "GoalActivity",
+            GoalTimestamp(_) => "GoalTimestamp",
+            FuzzySegment(_) => "FuzzySegment",
+            StaticSegment(_) => "StaticSegment",
+            IPGeoCountryState(_) => "IPGeoCountryState",
+            IPGeoIn(_) => "IPGeoIn",
+            IPGeoInZip(_) => "IPGeoInZip",
+            IPGeoUnknown(_) => "IPGeoUnknown",
+            IPGeoZip(_) => "IPGeoZip",
+            SocialAge(_) => "SocialAge",
+            SocialGender(_) => "SocialGender",
+            SocialInfluence(_) => "SocialInfluence",
+            SocialNetworkMember(_) => "SocialNetworkMember",
+            SocialNetworkFollow(_) => "SocialNetworkFollow",
+            AddressMerge(_) => "AddressMerge",
+            ZipMerge(_) => "ZipMerge",
+            BirthdayMerge(_) => "BirthdayMerge",
+            DateMerge(_) => "DateMerge",
+            SelectMerge(_) => "SelectMerge",
+            TextMerge(_) => "TextMerge",
+            EmailAddress(_) => "EmailAddress",
+            PredictedGender(_) => "PredictedGender",
+            PredictedAge(_) => "PredictedAge",
+            NewSubsribers(_) => "NewSubscribers",
+        }
+    }
+
+    ///
+    /// Field of the condition
+    ///
+    pub fn field(&self) -> &str {
+        match self {
+            SegmentConditionsType::VIP(c)
+            | SegmentConditionsType::EcommPurchased(c)
+            | SegmentConditionsType::IPGeoUnknown(c) => &c.field,
+            SegmentConditionsType::IPGeoIn(c) => &c.field,
+            other => &other.inner().unwrap().field,
+        }
+    }
+
+    ///
+    /// Operator of the condition
+    ///
+    pub fn op(&self) -> SegmentConditionOp {
+        match self {
+            SegmentConditionsType::VIP(c)
+            | SegmentConditionsType::EcommPurchased(c)
+            | SegmentConditionsType::IPGeoUnknown(c) => c.op.clone(),
+            SegmentConditionsType::IPGeoIn(c) => c.op.clone(),
+            other => other.inner().unwrap().op.clone(),
+        }
+    }
+
+    ///
+    /// Field, op, value and extra of the conditions that have them
+    ///
+    pub fn inner(&self) -> Option<&SegmentConditionInner> {
+        use SegmentConditionsType::*;
+        match self {
+            VIP(_) | EcommPurchased(_) | IPGeoUnknown(_) | IPGeoIn(_) => None,
+            Aim(c)
+            | Automation(c)
+            | CampaignPoll(c)
+            | Conversation(c)
+            | Date(c)
+            | EmailClient(c)
+            | Language(c)
+            | MemberRating(c)
+            | SignupSource(c)
+            | SurveyMonkey(c)
+            | Interests(c)
+            | EcommCategory(c)
+            | EcommNumber(c)
+            | EcommSpent(c)
+            | EcommStore(c)
+            | GoalActivity(c)
+            | GoalTimestamp(c)
+            | FuzzySegment(c)
+            | StaticSegment(c)
+            | IPGeoCountryState(c)
+            | IPGeoInZip(c)
+            | IPGeoZip(c)
+            | SocialAge(c)
+            | SocialGender(c)
+            | SocialInfluence(c)
+            | SocialNetworkMember(c)
+            | SocialNetworkFollow(c)
+            | AddressMerge(c)
+            | ZipMerge(c)
+            | BirthdayMerge(c)
+            | DateMerge(c)
+            | SelectMerge(c)
+            | TextMerge(c)
+            | EmailAddress(c)
+            | PredictedGender(c)
+            | PredictedAge(c)
+            | NewSubsribers(c) => Some(c),
+        }
+    }
+
+    fn from_value(condition_type: &str, value: Value) -> Result<Self, serde_json::Error> {
+        use serde_json::from_value as v;
+        use SegmentConditionsType::*;
+        Ok(match condition_type {
+            "Aim" => Aim(v(value)?),
+            "Automation" => Automation(v(value)?),
+            "CampaignPoll" => CampaignPoll(v(value)?),
+            "Conversation" => Conversation(v(value)?),
+            "Date" => Date(v(value)?),
+            "EmailClient" => EmailClient(v(value)?),
+            "Language" => Language(v(value)?),
+            "MemberRating" => MemberRating(v(value)?),
+            "SignupSource" => SignupSource(v(value)?),
+            "SurveyMonkey" => SurveyMonkey(v(value)?),
+            "VIP" => VIP(v(value)?),
+            "Interests" => Interests(v(value)?),
+            "EcommCategory" => EcommCategory(v(value)?),
+            "EcommNumber" => EcommNumber(v(value)?),
+            "EcommPurchased" => EcommPurchased(v(value)?),
+            "EcommSpent" => EcommSpent(v(value)?),
+            "EcommStore" => EcommStore(v(value)?),
+            "GoalActivity" => GoalActivity(v(value)?),
+            "GoalTimestamp" => GoalTimestamp(v(value)?),
+            "FuzzySegment" => FuzzySegment(v(value)?),
+            "StaticSegment" => StaticSegment(v(value)?),
+            "IPGeoCountryState" => IPGeoCountryState(v(value)?),
+            "IPGeoIn" => IPGeoIn(v(value)?),
+            "IPGeoInZip" => IPGeoInZip(v(value)?),
+            "IPGeoUnknown" => IPGeoUnknown(v(value)?),
+            "IPGeoZip" => IPGeoZip(v(value)?),
+            "SocialAge" => SocialAge(v(value)?),
+            "SocialGender" => SocialGender(v(value)?),
+            "SocialInfluence" => SocialInfluence(v(value)?),
+            "SocialNetworkMember" => SocialNetworkMember(v(value)?),
+            "SocialNetworkFollow" => SocialNetworkFollow(v(value)?),
+            "AddressMerge" => AddressMerge(v(value)?),
+            "ZipMerge" => ZipMerge(v(value)?),
+            "BirthdayMerge" => BirthdayMerge(v(value)?),
+            "DateMerge" => DateMerge(v(value)?),
+            "SelectMerge" => SelectMerge(v(value)?),
+            "TextMerge" => TextMerge(v(value)?),
+            "EmailAddress" => EmailAddress(v(value)?),
+            "PredictedGender" => PredictedGender(v(value)?),
+            "PredictedAge" => PredictedAge(v(value)?),
+            "NewSubscribers" | "NewSubsribers" => NewSubsribers(v(value)?),
+            other => {
+                return Err(serde_json::Error::custom(format!(
+                    "unknown condition_type `{}`",
+                    other
+                )))
+            }
+        })
+    }
+}
+
+///
+/// Guess the condition type of a condition that was returned without it
+///
+fn infer_condition_type(field: &str, op: &str) -> Option<&'static str> {
+    let condition_type = match (field, op) {
+        (_, "static_is") | (_, "static_not") | ("static_segment", _) => "StaticSegment",
+        (_, "fuzzy_is") | (_, "fuzzy_not") => "FuzzySegment",
+        (_, "ipgeocountry") | (_, "ipgeonotcountry") | (_, "ipgeostate") | (_, "ipgeonotstate") => {
+            "IPGeoCountryState"
+        }
+        (_, "ipgeoin") | (_, "ipgeonotin") => "IPGeoIn",
+        (_, "ipgeoinzip") => "IPGeoInZip",
+        (_, "ipgeounknown") => "IPGeoUnknown",
+        (_, "ipgeoiszip") | (_, "ipgeonotzip") => "IPGeoZip",
+        (_, "client_is") | (_, "client_not") => "EmailClient",
+        (_, "source_is") | (_, "source_not") => "SignupSource",
+        (_, "date_within") => "NewSubscribers",
+        (_, "geoin") => "ZipMerge",
+        ("aim", _) => "Aim",
+        ("automation", _) => "Automation",
+        ("poll", _) => "CampaignPoll",
+        ("conversation", _) => "Conversation",
+        ("timestamp_opt", _) | ("info_changed", _) | ("ecomm_date", _) => "Date",
+        ("language", _) => "Language",
+        ("rating", _) => "MemberRating",
+        ("survey", _) => "SurveyMonkey",
+        ("gmonkey", _) => "VIP",
+        ("ecomm_cat", _) | ("ecomm_prod", _) => "EcommCategory",
+        ("ecomm_purchased", _) => "EcommPurchased",
+        ("ecomm_store", _) => "EcommStore",
+        ("goal", _) => "GoalActivity",
+        ("goal_last_visited", _) => "GoalTimestamp",
+        ("predicted_gender", _) => "PredictedGender",
+        ("predicted_age_range", _) => "PredictedAge",
+        ("EMAIL", _) | ("merge0", _) => "EmailAddress",
+        ("social_age", _) => "SocialAge",
+        ("social_gender", _) => "SocialGender",
+        ("social_influence", _) => "SocialInfluence",
+        ("social_network", _) => "SocialNetworkMember",
+        (f, _) if f.starts_with("interests-") => "Interests",
+        (f, _) if f.starts_with("ecomm_spent") => "EcommSpent",
+        (f, _) if f.starts_with("ecomm_") => "EcommNumber",
+        (f, _) if f.starts_with("social_network") => "SocialNetworkFollow",
+        (f, _)
+            if f.starts_with("merge")
+                || f.chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_') =>
+        {
+            "TextMerge"
+        }
+        _ => return None,
+    };
+    Some(condition_type)
+}
+
+impl<'de> Deserialize<'de> for SegmentConditionsType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let condition_type = match value.get("condition_type").and_then(|c| c.as_str()) {
+            Some(c) => c.to_string(),
+            None => {
+                let field = value.get("field").and_then(|f| f.as_str()).unwrap_or("");
+                let op = value.get("op").and_then(|o| o.as_str()).unwrap_or("");
+                infer_condition_type(field, op)
+                    .ok_or_else(|| D::Error::missing_field("condition_type"))?
+                    .to_string()
+            }
+        };
+        SegmentConditionsType::from_value(&condition_type, value).map_err(D::Error::custom)
+    }
+}
+
+string_enum! {
+    ///
+    /// Segment Operator
+    ///
+    pub enum SegmentConditionOp {
+        // Aim conditions
+        /// The campaign was opened by the subscriber.
+        Open => "open",
+        /// The campaign was clicked by the subscriber.
+        Click => "click",
+        /// The campaign was sent to the subscriber.
+        Sent => "sent",
+        /// The campaign was not opened by the subscriber.
+        Noopen => "noopen",
+        /// The campaign was not clicked by the subscriber.
+        Noclick => "noclick",
+        /// The campaign was not sent to the subscriber.
+        Nosent => "nosent",
+
+        // Automation, SurveyMonkey conditions
+        /// The member has started the automation workflow or survey.
+        Started => "started",
+        /// The member has completed the automation workflow or survey.
+        Completed => "completed",
+        /// The member has not started the automation workflow or survey.
+        NotStarted => "not_started",
+        /// The member has not completed the automation workflow or survey.
+        NotCompleted => "not_completed",
+
+        // CampaignPoll, Conversation, SocialNetwork, VIP conditions
+        /// The subscriber is a member.
+        Member => "member",
+        /// The subscriber is not a member.
+        Notmember => "notmember",
+
+        // Date, etc. conditions
+        /// The field is greater than the value.
+        Greater => "greater",
+        /// The field is less than the value.
+        Less => "less",
+        /// The field is exactly the value.
+        Is => "is",
+        /// The field is not equal to the value.
+        Not => "not",
+        /// The field is blank.
+        Blank => "blank",
+        /// The field is not blank.
+        BlankNot => "blank_not",
+        /// The field is within the range.
+        Within => "within",
+        /// The field is not within the range.
+        Notwithin => "notwithin",
+
+        // EmailClient conditions
+        /// The email client is the value.
+        ClientIs => "client_is",
+        /// The email client is not the value.
+        ClientNot => "client_not",
+
+        // SignupSource conditions
+        /// The signup source is the value.
+        SourceIs => "source_is",
+        /// The signup source is not the value.
+        SourceNot => "source_not",
+
+        // EcommCategory, AddressMerge conditions
+        /// The field contains the value.
+        Contains => "contains",
+        /// The field does not contain the value.
+        Notcontain => "notcontain",
+        /// The field starts with the value.
+        Starts => "starts",
+        /// The field ends with the value.
+        Ends => "ends",
+
+        // Interests conditions
+        /// The member has one of the interests.
+        Interestcontains => "interestcontains",
+        /// The member has all the interests.
+        Interestcontainsall => "interestcontainsall",
+        /// The member has none of the interests.
+        Interestnotcontains => "interestnotcontains",
+
+        // Goal conditions
+        /// The goal is not the value. (Inverse of `Is` for Goal Segments.)
+        GoalNot => "goal_not",
+        /// The goal does not contain the value. (Inverse of `Contains` for Goal Segments.)
+        GoalNotcontain => "goal_notcontain",
+
+        // FuzzySegment conditions
+        /// The subscriber is in the value's "similar subscribers" segment.
+        FuzzyIs => "fuzzy_is",
+        /// The subscriber is not in the value's "similar subscribers" segment.
+        FuzzyNot => "fuzzy_not",
+
+        // StaticSegment conditions
+        /// The subscriber is has the value's tag.
+        StaticIs => "static_is",
+        /// The subscriber does not have the value's tag.
+        StaticNot => "static_not",
+
+        // IPGeoCountryState conditions
+        /// The subscriber is in the value's country code.
+        Ipgeocountry => "ipgeocountry",
+        /// The subscriber is not in the value's country code.
+        Ipgeonotcountry => "ipgeonotcountry",
+        /// The subscriber is in the value's state code.
+        Ipgeostate => "ipgeostate",
+        /// The subscriber is not in the value's state code.
+        Ipgeonotstate => "ipgeonotstate",
+
+        // IPGeoIn conditions
+        /// The subscriber is in a radius of {value} around {addr} or the {lat}/{lng} coordinates.
+        Ipgeoin => "ipgeoin",
+        /// The subscriber is not in a radius of {value} around {addr} or the {lat}/{lng} coordinates.
+        Ipgeonotin => "ipgeonotin",
+
+        // IPGeoInZip condition
+        /// The subscriber is in a radius of {value} around the ZIP code, {extra}.
+        Ipgeoinzip => "ipgeoinzip",
+
+        // IPGeoUnknown condition
+        /// The subscriber's location is unknown.
+        Ipgeounknown => "ipgeounknown",
+
+        // IPGeoZip conditions
+        /// The subscriber's ZIP code is the value.
+        Ipgeoiszip => "ipgeoiszip",
+        /// The subscriber's ZIP code is not the value.
+        Ipgeonotzip => "ipgeonotzip",
+
+        // SocialNetworkFollow conditions
+        /// The subscriber follows you on social media.
+        Follow => "follow",
+        /// The subscriber does not follow you on social media.
+        Notfollow => "notfollow",
+
+        // ZipMerge condition
+        /// The subscriber's address is {value} distance from the {extra} city or ZIP code.
+        Geoin => "geoin",
+
+        // NewSubscribers condition
+        /// The subscriber joined within a given time period.
+        DateWithin => "date_within",
+    }
 }
 
 ///
@@ -277,8 +645,10 @@ pub enum SegmentConditionOp {
 #[serde(default)]
 pub struct SegmentOptionsType {
     /// The id for an existing saved segment.
+    #[serde(skip_serializing_if = "is_zero")]
     pub saved_segment_id: u64,
     /// The prebuilt segment id, if a prebuilt segment has been designated for this campaign.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub prebuilt_segment_id: String,
     /// Desc: Segment match type.
     #[serde(rename = "match")]
@@ -286,3 +656,7 @@ pub struct SegmentOptionsType {
     /// An array of segment conditions.
     pub conditions: Vec<SegmentConditionsType>,
 }
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
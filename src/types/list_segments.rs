@@ -6,14 +6,20 @@ use super::list_batch_members::ListBatchErrors;
 use super::list_members::ListMember;
 use super::list_segment_members::{CollectionListSegmentMembers, ListSegmentMembersBuilder};
 use super::list_segment_options::SegmentOptionsType;
+use super::list_tags::BulkStaticSegmentResult;
+use super::subscriber_hash::SubscriberHash;
 use crate::api::MailchimpApi;
 use crate::internal::error_type::MailchimpErrorType;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter, SimpleFilter};
 use log::error;
 use std::collections::HashMap;
+use std::convert::TryInto;
 use std::rc::Rc;
 
+/// Maximum number of emails accepted by a static segment batch request
+pub const MAX_STATIC_SEGMENT_BATCH: usize = 500;
+
 ///
 /// List Segment
 ///
@@ -238,7 +244,7 @@ impl ResourceFilter for ListSegmentFilter {
         }
         if self.s_type.is_some() {
             payload.insert(
-                "s_type".to_string(),
+                "type".to_string(),
                 format!("{:}", self.s_type.as_ref().unwrap().clone()),
             );
         }
@@ -340,6 +346,41 @@ impl ListSegment {
             .post::<BatchListMembersStaticSegment, HashMap<String, Vec<String>>>(&endpoint, payload)
     }
 
+    ///
+    /// Batch add/remove many list members to a static segment
+    ///
+    /// The emails are sent in chunks of `chunk_size` (Mailchimp accepts up to
+    /// 500 per request) and the responses are aggregated. A failed request
+    /// doesn't stop the others, its emails are reported in `failed_requests`.
+    ///
+    /// Arguments:
+    ///     members_to_add: Emails to add to the static segment.
+    ///     members_to_remove: Emails to remove from the static segment.
+    ///     chunk_size: Emails per request.
+    ///
+    pub fn batch_members_in_chunks(
+        &self,
+        members_to_add: &[String],
+        members_to_remove: &[String],
+        chunk_size: usize,
+    ) -> BulkStaticSegmentResult {
+        let chunk_size = chunk_size.clamp(1, MAX_STATIC_SEGMENT_BATCH);
+        let mut result = BulkStaticSegmentResult::default();
+        for chunk in members_to_add.chunks(chunk_size) {
+            match self.create_bacth_to_static_segment(chunk.to_vec(), Vec::new()) {
+                Ok(response) => result.add_response(response),
+                Err(e) => result.add_failure(chunk.to_vec(), e),
+            }
+        }
+        for chunk in members_to_remove.chunks(chunk_size) {
+            match self.create_bacth_to_static_segment(Vec::new(), chunk.to_vec()) {
+                Ok(response) => result.add_response(response),
+                Err(e) => result.add_failure(chunk.to_vec(), e),
+            }
+        }
+        result
+    }
+
     ///
     /// Delete a specific segment in a list.
     ///
@@ -405,13 +446,22 @@ impl ListSegment {
     /// Remove a member from the specified static segment
     ///
     /// Arguments:
-    ///    subscriber_hash: The MD5 hash of the lowercase version of the list member’s email address.
-    ///
-    pub fn remove_member<'a>(&self, subscriber_hash: &'a str) -> Option<MailchimpErrorType> {
+    ///    member: The email address of the member or the MD5 hash of the lowercase
+    ///        version of the list member’s email address, see `SubscriberHash`.
+    ///
+    pub fn remove_member<H>(&self, member: H) -> Option<MailchimpErrorType>
+    where
+        H: TryInto<SubscriberHash>,
+        MailchimpErrorType: From<H::Error>,
+    {
         // DELETE /lists/{list_id}/segments/{segment_id}/members/{subscriber_hash}
+        let member = match member.try_into() {
+            Ok(member) => member,
+            Err(e) => return Some(e.into()),
+        };
         let mut endpoint = self.get_base_endpoint();
         endpoint.push_str("/members/");
-        endpoint.push_str(subscriber_hash);
+        endpoint.push_str(member.as_str());
 
         match self._api.delete::<EmptyType>(&endpoint, HashMap::new()) {
             Ok(_) => None,
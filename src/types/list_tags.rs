@@ -0,0 +1,93 @@
+use super::list_batch_members::ListBatchErrors;
+use super::list_segments::BatchListMembersStaticSegment;
+use crate::internal::error_type::MailchimpErrorType;
+use serde::{Deserialize, Serialize};
+
+///
+/// List Tag
+///
+/// Tags are static segments of the list, the id of the tag is the id of its
+/// segment.
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ListTag {
+    /// The unique id for the tag.
+    #[serde(default)]
+    pub id: u64,
+    /// The name of the tag.
+    #[serde(default)]
+    pub name: String,
+}
+
+///
+/// Response for endpoint GET /lists/{list_id}/tag-search
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionListTags {
+    /// A list of matching tags.
+    #[serde(default)]
+    pub tags: Vec<ListTag>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+}
+
+///
+/// Aggregated result of a bulk add/remove of members to a static segment
+/// made in several requests
+///
+#[derive(Debug, Clone, Default)]
+pub struct BulkStaticSegmentResult {
+    /// Number of requests sent
+    pub requests: u64,
+    /// The total number of members added to the segment.
+    pub total_added: u64,
+    /// The total number of members removed from the segment.
+    pub total_removed: u64,
+    /// Email addresses that could not be added or removed and why.
+    pub errors: Vec<ListBatchErrors>,
+    /// Requests that failed, with the emails of the chunk that was not applied.
+    pub failed_requests: Vec<(Vec<String>, MailchimpErrorType)>,
+}
+
+impl BulkStaticSegmentResult {
+    ///
+    /// Add the response of one of the requests
+    ///
+    pub fn add_response(&mut self, response: BatchListMembersStaticSegment) {
+        self.requests += 1;
+        self.total_added += response.total_added;
+        self.total_removed += response.total_removed;
+        self.errors.extend(response.errors);
+    }
+
+    ///
+    /// Add a request that failed
+    ///
+    pub fn add_failure(&mut self, emails: Vec<String>, error: MailchimpErrorType) {
+        self.requests += 1;
+        self.failed_requests.push((emails, error));
+    }
+
+    ///
+    /// Return true when every email was applied without errors
+    ///
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty() && self.failed_requests.is_empty()
+    }
+
+    ///
+    /// Email addresses that were not applied, by a member error or a failed request
+    ///
+    pub fn failed_emails(&self) -> Vec<String> {
+        let mut emails: Vec<String> = self
+            .errors
+            .iter()
+            .map(|e| e.email_address.clone())
+            .collect();
+        for (chunk, _) in &self.failed_requests {
+            emails.extend(chunk.iter().cloned());
+        }
+        emails
+    }
+}
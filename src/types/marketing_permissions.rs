@@ -0,0 +1,124 @@
+//! Implement the helpers for the marketing permissions (GDPR) of a list
+//!
+//! Mailchimp doesn't expose the permissions of a list on their own endpoint,
+//! every member carries all of them with its own consent, so the definitions
+//! are read from the members.
+//!
+
+use super::csv::csv_field;
+use super::list_members::{ListMarketingPermision, PartialListMember};
+#[cfg(feature = "chrono")]
+use super::timestamp::parse_timestamp;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+
+///
+/// Marketing permission defined on a list
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct MarketingPermission {
+    /// The id for the marketing permission on the list
+    pub marketing_permission_id: String,
+    /// The text of the marketing permission.
+    pub text: String,
+}
+
+impl<'a> From<&'a ListMarketingPermision> for MarketingPermission {
+    fn from(value: &'a ListMarketingPermision) -> Self {
+        MarketingPermission {
+            marketing_permission_id: value.marketing_permission_id.clone(),
+            text: value.text.clone(),
+        }
+    }
+}
+
+///
+/// Find a permission by its text (case-insensitive) or id
+///
+pub fn find_marketing_permission<'a>(
+    permissions: &'a [ListMarketingPermision],
+    text: &str,
+) -> Option<&'a ListMarketingPermision> {
+    let text = text.trim();
+    permissions
+        .iter()
+        .find(|p| p.text.trim().eq_ignore_ascii_case(text) || p.marketing_permission_id == text)
+}
+
+///
+/// Consent of a member for a marketing permission
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ConsentRecord {
+    /// Email address of the member
+    pub email_address: String,
+    /// The id for the marketing permission on the list
+    pub marketing_permission_id: String,
+    /// The text of the marketing permission.
+    pub text: String,
+    /// If the member has opted-in to the marketing permission.
+    pub enabled: bool,
+    /// The date and time the member’s info was last changed. Mailchimp
+    /// doesn't return when each consent was given, so this is the member's
+    /// `last_changed` and is the same for all of its records.
+    pub member_last_changed: Option<String>,
+}
+
+impl ConsentRecord {
+    ///
+    /// `member_last_changed` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn member_last_changed_utc(&self) -> Option<DateTime<Utc>> {
+        self.member_last_changed
+            .as_deref()
+            .and_then(parse_timestamp)
+    }
+
+    ///
+    /// One record for each marketing permission of the member
+    ///
+    pub fn from_member(member: &PartialListMember) -> Vec<ConsentRecord> {
+        let email = member.email_address.clone().unwrap_or_default();
+        member
+            .marketing_permissions
+            .iter()
+            .flatten()
+            .map(|p| ConsentRecord {
+                email_address: email.clone(),
+                marketing_permission_id: p.marketing_permission_id.clone(),
+                text: p.text.clone(),
+                enabled: p.enabled,
+                member_last_changed: member.last_changed.clone(),
+            })
+            .collect()
+    }
+}
+
+///
+/// Write the consent records as CSV, with a header row
+///
+/// Arguments:
+///     records: Consent records, see `ListType::consent_audit`
+///     writer: Destination of the CSV
+///
+pub fn write_consent_csv<W: Write>(records: &[ConsentRecord], mut writer: W) -> io::Result<()> {
+    writeln!(
+        writer,
+        "email_address,marketing_permission_id,text,enabled,member_last_changed"
+    )?;
+    for r in records {
+        writeln!(
+            writer,
+            "{},{},{},{},{}",
+            csv_field(&r.email_address),
+            csv_field(&r.marketing_permission_id),
+            csv_field(&r.text),
+            r.enabled,
+            csv_field(r.member_last_changed.as_deref().unwrap_or_default())
+        )?;
+    }
+    Ok(())
+}
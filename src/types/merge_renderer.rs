@@ -0,0 +1,367 @@
+//! Local rendering of the merge tags of the campaign content for a list member,
+//! see `ListType::merge_renderer`
+//!
+//! Replaces the merge fields of the member, `*|EMAIL|*` and the list tags, and
+//! evaluates the `*|IF:|*`, `*|IFNOT:|*`, `*|ELSEIF:|*`, `*|ELSE:|*` and
+//! `*|INTERESTED:|*` blocks. The tags that can't be resolved locally, like
+//! `*|UNSUB|*`, are kept as they are unless they are set with `MergeRenderer::tag`.
+//!
+//! ```
+//!     use mailchimp::types::{ListMember, MergeRenderer};
+//!
+//!     let member: ListMember = serde_json::from_str(
+//!         r#"{"email_address": "freddie@freddiesjokes.com", "merge_fields": {"FNAME": "Freddie"}}"#,
+//!     )
+//!     .unwrap();
+//!
+//!     let html = "Hi *|IF:FNAME|**|FNAME|**|ELSE:|*there*|END:IF|* (*|EMAIL|*)";
+//!     let rendered = MergeRenderer::new().render(html, &member).unwrap();
+//!     assert_eq!(rendered, "Hi Freddie (freddie@freddiesjokes.com)");
+//! ```
+//!
+
+use super::contact::ContactType;
+use super::list::ListType;
+use super::list_members::ListMember;
+use super::list_merge_fields::ListMergeField;
+use super::merge_tags::{find_merge_tags, split_condition, MergeTag, MergeTagIssue};
+use super::timestamp::civil_from_days;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(PartialEq)]
+enum BlockKind {
+    If,
+    Interested,
+}
+
+struct Block {
+    kind: BlockKind,
+    tag: String,
+    line: usize,
+    /// The content around the block is rendered
+    parent_active: bool,
+    /// A branch of the block was already rendered
+    matched: bool,
+    /// The current branch is rendered
+    active: bool,
+}
+
+///
+/// Render the merge tags of the content for a list member
+///
+#[derive(Debug, Clone)]
+pub struct MergeRenderer {
+    tags: HashMap<String, String>,
+    fields: Vec<ListMergeField>,
+    // (category title, interest name, interest id)
+    interests: Vec<(String, String, String)>,
+    escape_html: bool,
+}
+
+impl Default for MergeRenderer {
+    fn default() -> Self {
+        MergeRenderer {
+            tags: HashMap::new(),
+            fields: Vec::new(),
+            interests: Vec::new(),
+            escape_html: true,
+        }
+    }
+}
+
+impl MergeRenderer {
+    ///
+    /// Renderer without list tags, merge field definitions nor interests
+    ///
+    pub fn new() -> Self {
+        MergeRenderer::default()
+    }
+
+    ///
+    /// Value of a merge tag, e.g. `UNSUB` or `LIST:NAME`
+    ///
+    pub fn tag(mut self, tag: &str, value: &str) -> Self {
+        self.tags
+            .insert(tag.trim().to_uppercase(), value.to_string());
+        self
+    }
+
+    ///
+    /// The `*|LIST:...|*` tags of the list and its contact
+    ///
+    pub fn list(mut self, list: &ListType) -> Self {
+        let values = [
+            ("LIST:NAME", &list.name),
+            ("LIST:DESCRIPTION", &list.permission_reminder),
+            ("LIST:SUBSCRIBE", &list.subscribe_url_short),
+        ];
+        for (tag, value) in values.iter() {
+            if let Some(value) = value {
+                self = self.tag(tag, value);
+            }
+        }
+        match &list.contact {
+            Some(contact) => self.contact(contact),
+            None => self,
+        }
+    }
+
+    ///
+    /// The `*|LIST:COMPANY|*`, `*|LIST:ADDRESS|*`, `*|LIST:ADDRESSLINE|*` and
+    /// `*|LIST:PHONE|*` tags of the contact of the list
+    ///
+    pub fn contact(self, contact: &ContactType) -> Self {
+        let address: Vec<&str> = [
+            &contact.address1,
+            &contact.address2,
+            &contact.city,
+            &contact.state,
+            &contact.zip,
+            &contact.country,
+        ]
+        .iter()
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+        .collect();
+        self.tag("LIST:COMPANY", &contact.company)
+            .tag("LIST:ADDRESS", &address.join("\n"))
+            .tag("LIST:ADDRESSLINE", &address.join(", "))
+            .tag("LIST:PHONE", &contact.phone)
+    }
+
+    ///
+    /// Merge fields of the list, used for the `*|MERGEn|*` tags and the default values
+    ///
+    pub fn merge_fields(mut self, fields: Vec<ListMergeField>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    ///
+    /// Interest of the list, used by the `*|INTERESTED:|*` blocks
+    ///
+    /// Arguments:
+    ///     category: Title of the interest category
+    ///     name: Name of the interest
+    ///     interest_id: The id of the interest
+    ///
+    pub fn interest(mut self, category: &str, name: &str, interest_id: &str) -> Self {
+        self.interests.push((
+            category.trim().to_string(),
+            name.trim().to_string(),
+            interest_id.to_string(),
+        ));
+        self
+    }
+
+    ///
+    /// Escape `&`, `<`, `>` and `"` in the values, true by default. Disable it
+    /// to render the plain-text content.
+    ///
+    pub fn escape_html(mut self, escape: bool) -> Self {
+        self.escape_html = escape;
+        self
+    }
+
+    ///
+    /// Render the content for the member. Fails when the conditional blocks
+    /// are not balanced, see `lint_merge_tags`.
+    ///
+    pub fn render(&self, content: &str, member: &ListMember) -> Result<String, MergeTagIssue> {
+        let mut out = String::new();
+        let mut blocks: Vec<Block> = Vec::new();
+        let mut position = 0;
+        for merge_tag in find_merge_tags(content) {
+            let active = blocks.last().map(|b| b.active).unwrap_or(true);
+            if active {
+                out.push_str(&content[position..merge_tag.start]);
+            }
+            position = merge_tag.end;
+
+            let unbalanced = |reason: &str| MergeTagIssue::UnbalancedConditional {
+                tag: merge_tag.tag.clone(),
+                line: merge_tag.line,
+                reason: reason.to_string(),
+            };
+            let name = merge_tag.name();
+            // The upper case name may not have the byte offsets of the tag
+            let (keyword, argument) = match (name.find(':'), merge_tag.tag.find(':')) {
+                (Some(i), Some(j)) => (&name[..i], merge_tag.tag[j + 1..].trim()),
+                _ => (name.as_str(), ""),
+            };
+            match keyword {
+                "IF" | "IFNOT" | "INTERESTED" if argument.is_empty() => {
+                    return Err(unbalanced("has no condition"))
+                }
+                "IF" | "IFNOT" | "INTERESTED" => {
+                    let matched = active && self.condition(keyword, argument, member);
+                    blocks.push(Block {
+                        kind: if keyword == "INTERESTED" {
+                            BlockKind::Interested
+                        } else {
+                            BlockKind::If
+                        },
+                        tag: merge_tag.tag.clone(),
+                        line: merge_tag.line,
+                        parent_active: active,
+                        matched,
+                        active: matched,
+                    });
+                }
+                "ELSEIF" => match blocks.last_mut() {
+                    Some(b) if b.kind == BlockKind::If => {
+                        b.active =
+                            b.parent_active && !b.matched && self.condition("IF", argument, member);
+                        b.matched = b.matched || b.active;
+                    }
+                    _ => return Err(unbalanced("has no matching *|IF:|*")),
+                },
+                "ELSE" => match blocks.last_mut() {
+                    Some(b) => {
+                        b.active = b.parent_active && !b.matched;
+                        b.matched = true;
+                    }
+                    None => return Err(unbalanced("has no matching *|IF:|*")),
+                },
+                "END"
+                    if argument.eq_ignore_ascii_case("IF")
+                        || argument.eq_ignore_ascii_case("INTERESTED") =>
+                {
+                    let kind = if argument.eq_ignore_ascii_case("IF") {
+                        BlockKind::If
+                    } else {
+                        BlockKind::Interested
+                    };
+                    match blocks.pop() {
+                        Some(b) if b.kind == kind => {}
+                        Some(b) => {
+                            return Err(unbalanced(&format!(
+                                "closes the block *|{}|* opened on line {}",
+                                b.tag, b.line
+                            )))
+                        }
+                        None => return Err(unbalanced("closes a block that was never opened")),
+                    }
+                }
+                _ if active => match self.value(&merge_tag, member) {
+                    Some(value) if self.escape_html => out.push_str(&escape(&value)),
+                    Some(value) => out.push_str(&value),
+                    None => out.push_str(&content[merge_tag.start..merge_tag.end]),
+                },
+                _ => {}
+            }
+        }
+        if let Some(b) = blocks.pop() {
+            return Err(MergeTagIssue::UnbalancedConditional {
+                tag: b.tag,
+                line: b.line,
+                reason: "is never closed".to_string(),
+            });
+        }
+        out.push_str(&content[position..]);
+        Ok(out)
+    }
+
+    /// Evaluate the condition of an IF, IFNOT or INTERESTED tag
+    fn condition(&self, keyword: &str, argument: &str, member: &ListMember) -> bool {
+        if keyword == "INTERESTED" {
+            let (category, names) = match argument.find(':') {
+                Some(i) => (argument[..i].trim(), argument[i + 1..].trim()),
+                None => (argument, ""),
+            };
+            let names: Vec<&str> = names
+                .split(',')
+                .map(|n| n.trim())
+                .filter(|n| !n.is_empty())
+                .collect();
+            return self
+                .interests
+                .iter()
+                .filter(|(c, n, _)| {
+                    c.eq_ignore_ascii_case(category)
+                        && (names.is_empty()
+                            || names.iter().any(|name| n.eq_ignore_ascii_case(name)))
+                })
+                .any(|(_, _, id)| member.interests.get(id).cloned().unwrap_or(false));
+        }
+
+        let (field, comparison) = split_condition(argument);
+        let value = self
+            .field_value(&field.to_uppercase(), member)
+            .unwrap_or_default();
+        let result = match comparison {
+            None => !value.trim().is_empty(),
+            Some((operator, expected)) => {
+                let ordering = match (value.trim().parse::<f64>(), expected.parse::<f64>()) {
+                    (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                    _ => value.trim().to_lowercase().cmp(&expected.to_lowercase()),
+                };
+                match operator {
+                    "=" => ordering == Ordering::Equal,
+                    "!=" => ordering != Ordering::Equal,
+                    ">" => ordering == Ordering::Greater,
+                    "<" => ordering == Ordering::Less,
+                    ">=" => ordering != Ordering::Less,
+                    _ => ordering != Ordering::Greater,
+                }
+            }
+        };
+        if keyword == "IFNOT" {
+            !result
+        } else {
+            result
+        }
+    }
+
+    /// Value of a merge tag that isn't a conditional
+    fn value(&self, merge_tag: &MergeTag, member: &ListMember) -> Option<String> {
+        let name = merge_tag.name();
+        match name.as_str() {
+            "CURRENT_YEAR" if !self.tags.contains_key(&name) => {
+                let seconds = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs() as i64)
+                    .unwrap_or(0);
+                Some(civil_from_days(seconds / 86_400).0.to_string())
+            }
+            _ => self.field_value(&name, member),
+        }
+    }
+
+    /// Value of a merge field of the member, `EMAIL` or a tag set on the renderer
+    fn field_value(&self, name: &str, member: &ListMember) -> Option<String> {
+        if name == "EMAIL" {
+            return Some(member.email_address.clone());
+        }
+        if let Some(value) = self.tags.get(name) {
+            return Some(value.clone());
+        }
+        let field = self
+            .fields
+            .iter()
+            .find(|f| f.tag.eq_ignore_ascii_case(name) || format!("MERGE{}", f.merge_id) == name);
+        let tag = field.map(|f| f.tag.as_str()).unwrap_or(name);
+        let value = member
+            .merge_fields
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(tag))
+            .map(|(_, v)| v.to_string());
+        match (value, field) {
+            (Some(v), Some(f)) if v.is_empty() => Some(f.default_value.clone()),
+            (Some(v), _) => Some(v),
+            (None, Some(f)) => Some(f.default_value.clone()),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Escape the value to insert it in HTML
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,501 @@
+//! Merge tags of the campaign content: plain-text generation from the HTML and
+//! linting of the `*|TAG|*` merge tags against the merge fields of a list, see
+//! `ListType::lint_content`
+//!
+//! ```
+//!     use mailchimp::types::{html_to_text, lint_merge_tags, MergeTagIssue};
+//!
+//!     let html = "<p>Hi *|FNAME|*,</p>*|IF:VIP|*<p>Thanks!</p>";
+//!     assert_eq!(html_to_text(html), "Hi *|FNAME|*,\n\n*|IF:VIP|*\n\nThanks!");
+//!
+//!     let issues = lint_merge_tags(html, &[]);
+//!     assert!(issues.contains(&MergeTagIssue::MissingUnsubscribe));
+//! ```
+//!
+
+use super::list_merge_fields::ListMergeField;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Merge tags provided by Mailchimp for every list
+const SYSTEM_TAGS: &[&str] = &[
+    "EMAIL",
+    "UNSUB",
+    "UPDATE_PROFILE",
+    "FORWARD",
+    "ARCHIVE",
+    "ARCHIVE_LINK_SHORT",
+    "CURRENT_YEAR",
+    "DATE",
+    "MC_PREVIEW_TEXT",
+    "MC_SUBJECT",
+    "MC_LANGUAGE",
+    "MC_LANGUAGE_LABEL",
+    "ABOUT_LIST",
+    "REWARDS",
+    "REWARDS_TEXT",
+    "LIST_ADDRESS",
+    "LIST_ADDRESS_HTML",
+    "HTML_LIST_ADDRESS_HTML",
+    "LIST_NAME",
+    "LIST_COMPANY",
+    "LIST_DESCRIPTION",
+    "LIST_RECIPIENTS",
+    "CAMPAIGN_UID",
+    "UNIQID",
+    "EMAIL_UID",
+    "ABUSE_EMAIL",
+    "MEMBER_RATING",
+    "SUBJECT",
+    "END:RSSITEMS",
+];
+
+/// Prefixes of the families of merge tags that take an argument
+const PREFIX_FAMILIES: &[&str] = &[
+    "LIST",
+    "MC",
+    "DATE",
+    "RSSFEED",
+    "RSSITEM",
+    "RSSITEMS",
+    "USER",
+    "TWITTER",
+    "FACEBOOK",
+    "TRANSLATE",
+    "HTML",
+    "SHARE",
+    "GROUPS",
+];
+
+///
+/// A merge tag found in the content
+///
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MergeTag {
+    /// The content between `*|` and `|*`, trimmed
+    pub tag: String,
+    /// Line of the tag, starting at 1
+    pub line: usize,
+    /// Byte offset of `*|`
+    pub start: usize,
+    /// Byte offset after `|*`
+    pub end: usize,
+}
+
+impl MergeTag {
+    /// The tag in upper case, merge tags are case-insensitive
+    pub fn name(&self) -> String {
+        self.tag.to_uppercase()
+    }
+}
+
+///
+/// Find the merge tags of the content, in order
+///
+pub(crate) fn find_merge_tags(content: &str) -> Vec<MergeTag> {
+    let mut tags = Vec::new();
+    let mut line = 1;
+    let mut position = 0;
+    while let Some(found) = content[position..].find("*|") {
+        let start = position + found;
+        let close = match content[start + 2..].find("|*") {
+            Some(close) => start + 2 + close,
+            None => break,
+        };
+        line += content[position..start].matches('\n').count();
+        tags.push(MergeTag {
+            tag: content[start + 2..close].trim().to_string(),
+            line,
+            start,
+            end: close + 2,
+        });
+        line += content[start..close + 2].matches('\n').count();
+        position = close + 2;
+    }
+    tags
+}
+
+///
+/// Name of the merge field of a condition, e.g. `FNAME` for `FNAME=Freddie`
+///
+pub(crate) fn split_condition(condition: &str) -> (&str, Option<(&str, &str)>) {
+    for operator in ["!=", ">=", "<=", "=", ">", "<"].iter() {
+        if let Some(i) = condition.find(operator) {
+            return (
+                condition[..i].trim(),
+                Some((operator, condition[i + operator.len()..].trim())),
+            );
+        }
+    }
+    (condition.trim(), None)
+}
+
+///
+/// Problems found in the merge tags of the content
+///
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum MergeTagIssue {
+    /// The tag is not a merge field of the list nor a Mailchimp merge tag
+    UnknownTag {
+        /// The merge tag, without `*|` and `|*`
+        tag: String,
+        /// Line of the tag, starting at 1
+        line: usize,
+    },
+    /// A conditional block is not opened or closed properly
+    UnbalancedConditional {
+        /// The merge tag, without `*|` and `|*`
+        tag: String,
+        /// Line of the tag, starting at 1
+        line: usize,
+        /// What is wrong with the block
+        reason: String,
+    },
+    /// The content has no `*|UNSUB|*` link
+    MissingUnsubscribe,
+}
+
+impl fmt::Display for MergeTagIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MergeTagIssue::UnknownTag { tag, line } => {
+                write!(f, "line {}: unknown merge tag *|{}|*", line, tag)
+            }
+            MergeTagIssue::UnbalancedConditional { tag, line, reason } => {
+                write!(f, "line {}: *|{}|* {}", line, tag, reason)
+            }
+            MergeTagIssue::MissingUnsubscribe => write!(f, "the content has no *|UNSUB|* link"),
+        }
+    }
+}
+
+#[derive(PartialEq)]
+enum BlockKind {
+    If,
+    Interested,
+}
+
+struct OpenBlock {
+    kind: BlockKind,
+    tag: String,
+    line: usize,
+    has_else: bool,
+}
+
+///
+/// Lint the merge tags of the content against the merge fields of the list
+///
+/// Reports the tags that are not merge fields of the list nor Mailchimp merge
+/// tags, the `*|IF:|*` and `*|INTERESTED:|*` blocks that are not balanced and
+/// a missing `*|UNSUB|*` link.
+///
+/// Arguments:
+///     content: HTML or plain-text content of the campaign
+///     fields: Merge fields of the list, see `ListType::get_merge_fields`
+///
+pub fn lint_merge_tags(content: &str, fields: &[ListMergeField]) -> Vec<MergeTagIssue> {
+    let mut known: HashSet<String> = SYSTEM_TAGS.iter().map(|t| t.to_string()).collect();
+    for field in fields {
+        known.insert(field.tag.to_uppercase());
+        known.insert(format!("MERGE{}", field.merge_id));
+    }
+    let is_known = |name: &str| {
+        known.contains(name)
+            || name
+                .find(':')
+                .map(|i| PREFIX_FAMILIES.contains(&&name[..i]))
+                .unwrap_or(false)
+    };
+
+    let mut issues = Vec::new();
+    let mut blocks: Vec<OpenBlock> = Vec::new();
+    let mut has_unsubscribe = false;
+    for merge_tag in find_merge_tags(content) {
+        let name = merge_tag.name();
+        let line = merge_tag.line;
+        let unbalanced = |reason: &str| MergeTagIssue::UnbalancedConditional {
+            tag: merge_tag.tag.clone(),
+            line,
+            reason: reason.to_string(),
+        };
+        let (keyword, argument) = match name.find(':') {
+            Some(i) => (&name[..i], Some(&name[i + 1..])),
+            None => (name.as_str(), None),
+        };
+        match (keyword, argument) {
+            ("IF", Some(condition)) | ("IFNOT", Some(condition)) | ("ELSEIF", Some(condition)) => {
+                let (field, _) = split_condition(condition);
+                if !is_known(field) {
+                    issues.push(MergeTagIssue::UnknownTag {
+                        tag: field.to_string(),
+                        line,
+                    });
+                }
+                if keyword == "ELSEIF" {
+                    match blocks.last() {
+                        Some(b) if b.kind == BlockKind::If && !b.has_else => {}
+                        Some(b) if b.kind == BlockKind::If => {
+                            issues.push(unbalanced("comes after *|ELSE:|*"))
+                        }
+                        _ => issues.push(unbalanced("has no matching *|IF:|*")),
+                    }
+                } else {
+                    blocks.push(OpenBlock {
+                        kind: BlockKind::If,
+                        tag: merge_tag.tag.clone(),
+                        line,
+                        has_else: false,
+                    });
+                }
+            }
+            // ELSE closes the first branch of an IF or an INTERESTED block
+            ("ELSE", _) => match blocks.last_mut() {
+                Some(b) if !b.has_else => b.has_else = true,
+                Some(_) => issues.push(unbalanced("comes after another *|ELSE:|*")),
+                None => issues.push(unbalanced("has no matching *|IF:|*")),
+            },
+            ("INTERESTED", Some(_)) => blocks.push(OpenBlock {
+                kind: BlockKind::Interested,
+                tag: merge_tag.tag.clone(),
+                line,
+                has_else: false,
+            }),
+            ("END", Some("IF")) | ("END", Some("INTERESTED")) => {
+                let kind = if argument == Some("IF") {
+                    BlockKind::If
+                } else {
+                    BlockKind::Interested
+                };
+                match blocks.last() {
+                    Some(b) if b.kind == kind => {
+                        blocks.pop();
+                    }
+                    Some(b) => issues.push(unbalanced(&format!(
+                        "closes the block *|{}|* opened on line {}",
+                        b.tag, b.line
+                    ))),
+                    None => issues.push(unbalanced("closes a block that was never opened")),
+                }
+            }
+            ("IF", None) | ("IFNOT", None) | ("ELSEIF", None) | ("INTERESTED", None) => {
+                issues.push(unbalanced("has no condition"))
+            }
+            _ => {
+                if name == "UNSUB" {
+                    has_unsubscribe = true;
+                }
+                if !is_known(&name) {
+                    issues.push(MergeTagIssue::UnknownTag {
+                        tag: merge_tag.tag.clone(),
+                        line,
+                    });
+                }
+            }
+        }
+    }
+    for block in blocks {
+        issues.push(MergeTagIssue::UnbalancedConditional {
+            tag: block.tag,
+            line: block.line,
+            reason: "is never closed".to_string(),
+        });
+    }
+    if !has_unsubscribe {
+        issues.push(MergeTagIssue::MissingUnsubscribe);
+    }
+    issues
+}
+
+///
+/// Plain-text alternative of the HTML content
+///
+/// Drops the head, styles and scripts, puts block elements on their own
+/// paragraphs and list items on `* ` lines, appends the url to the text of the
+/// links and decodes the common entities. The merge tags are kept as they are.
+///
+pub fn html_to_text(html: &str) -> String {
+    let mut out = String::new();
+    let mut link: Option<(String, usize)> = None;
+    let mut position = 0;
+    while position < html.len() {
+        let rest = &html[position..];
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            push_text(&mut out, &rest[..end]);
+            position += end;
+            continue;
+        }
+        if rest.starts_with("<!--") {
+            position += rest.find("-->").map(|i| i + 3).unwrap_or(rest.len());
+            continue;
+        }
+        let end = match rest.find('>') {
+            Some(end) => end,
+            None => {
+                push_text(&mut out, rest);
+                break;
+            }
+        };
+        let element = &rest[1..end];
+        position += end + 1;
+
+        let closing = element.starts_with('/');
+        let name = element
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        match name.as_str() {
+            "head" | "style" | "script" | "title" if !closing => {
+                let closing_tag = format!("</{}", name);
+                position += find_ignore_ascii_case(&html[position..], &closing_tag)
+                    .map(|i| {
+                        let tail = &html[position + i..];
+                        i + tail.find('>').map(|j| j + 1).unwrap_or(tail.len())
+                    })
+                    .unwrap_or(html.len() - position);
+            }
+            "br" => out.push('\n'),
+            "li" if !closing => out.push_str("\n* "),
+            "td" | "th" if closing => out.push(' '),
+            "p" | "div" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "tr" | "table" | "ul"
+            | "ol" | "blockquote" | "hr" | "section" | "header" | "footer" | "center" => {
+                out.push_str("\n\n")
+            }
+            "a" if !closing => link = attribute(element, "href").map(|href| (href, out.len())),
+            "a" => {
+                if let Some((href, start)) = link.take() {
+                    let text = out[start..].trim().to_string();
+                    let href = href.trim_start_matches("mailto:");
+                    if !href.is_empty() && !href.starts_with('#') && text != href {
+                        out.push_str(&format!(" ({})", href));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // One blank line at most between paragraphs
+    let mut lines: Vec<&str> = Vec::new();
+    for line in out.lines().map(|l| l.trim()) {
+        if !line.is_empty() || lines.last().map(|l| !l.is_empty()).unwrap_or(false) {
+            lines.push(line);
+        }
+    }
+    lines.join("\n").trim_end().to_string()
+}
+
+/// Append the text of the HTML collapsing the whitespace
+fn push_text(out: &mut String, text: &str) {
+    let decoded = decode_entities(text);
+    for c in decoded.chars() {
+        if c.is_whitespace() && c != '\u{a0}' {
+            if !out.ends_with(|p: char| p.is_whitespace()) && !out.is_empty() {
+                out.push(' ');
+            }
+        } else if c == '\u{a0}' {
+            out.push(' ');
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+/// Byte offset of the first match of the ASCII `needle` in `haystack`, ignoring
+/// the ASCII case. Unlike searching a lowercase copy, the offset is always
+/// valid in `haystack`.
+fn find_ignore_ascii_case(haystack: &str, needle: &str) -> Option<usize> {
+    let needle = needle.as_bytes();
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))
+}
+
+/// Value of the attribute of an element
+fn attribute(element: &str, name: &str) -> Option<String> {
+    let mut from = 0;
+    while let Some(i) = find_ignore_ascii_case(&element[from..], name) {
+        let at = from + i;
+        from = at + name.len();
+        let before = element[..at].chars().last();
+        if !before.map(|c| c.is_whitespace()).unwrap_or(false) {
+            continue;
+        }
+        let rest = element[from..].trim_start();
+        if !rest.starts_with('=') {
+            continue;
+        }
+        let rest = rest[1..].trim_start();
+        let value = match rest.chars().next() {
+            Some(quote) if quote == '"' || quote == '\'' => {
+                rest[1..].split(quote).next().unwrap_or("")
+            }
+            _ => rest.split(char::is_whitespace).next().unwrap_or(""),
+        };
+        return Some(decode_entities(value));
+    }
+    None
+}
+
+/// Decode the named entities in common use and the numeric ones
+fn decode_entities(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let end = match rest.find(';') {
+            Some(end) if end <= 10 => end,
+            _ => {
+                out.push('&');
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            "copy" => Some('©'),
+            "reg" => Some('®'),
+            "trade" => Some('™'),
+            "mdash" => Some('—'),
+            "ndash" => Some('–'),
+            "hellip" => Some('…'),
+            "lsquo" => Some('‘'),
+            "rsquo" => Some('’'),
+            "ldquo" => Some('“'),
+            "rdquo" => Some('”'),
+            _ if entity.starts_with("#x") || entity.starts_with("#X") => {
+                u32::from_str_radix(&entity[2..], 16)
+                    .ok()
+                    .and_then(std::char::from_u32)
+            }
+            _ if entity.starts_with('#') => entity[1..]
+                .parse::<u32>()
+                .ok()
+                .and_then(std::char::from_u32),
+            _ => None,
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
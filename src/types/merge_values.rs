@@ -0,0 +1,338 @@
+//! Implement the merge field values of a list member
+//!
+//! Mailchimp returns the merge fields as strings, numbers or, for the ADDRESS
+//! fields, as nested objects.
+//!
+
+use super::list_merge_fields::ListMergeField;
+use serde::{Deserialize, Serialize};
+use serde_json::Number;
+use std::collections::HashMap;
+use std::fmt;
+
+///
+/// Value of an ADDRESS merge field
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct MergeAddress {
+    /// Street address
+    #[serde(default)]
+    pub addr1: String,
+    /// Address line 2
+    #[serde(default)]
+    pub addr2: String,
+    /// City
+    #[serde(default)]
+    pub city: String,
+    /// State or province
+    #[serde(default)]
+    pub state: String,
+    /// Zip or postal code
+    #[serde(default)]
+    pub zip: String,
+    /// Two-letter country code
+    #[serde(default)]
+    pub country: String,
+}
+
+///
+/// Value of a merge field
+///
+/// The values returned by the API are deserialized as `Text`, `Number` or
+/// `Address`; use `MergeValue::typed` to get the variant that matches the merge
+/// field definition. Every variant except `Number` and `Address` is sent as a
+/// string. Numbers keep their JSON representation, so an integer is sent back
+/// as an integer.
+///
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum MergeValue {
+    /// Text value
+    Text(String),
+    /// Number value
+    Number(Number),
+    /// Address value
+    Address(MergeAddress),
+    /// Date, in the date format of the merge field
+    Date(String),
+    /// Birthday (MM/DD or DD/MM)
+    Birthday(String),
+    /// Phone number
+    Phone(String),
+    /// Website url
+    Url(String),
+    /// Image url
+    Image(String),
+    /// One of the choices of a dropdown or radio merge field
+    Choice(String),
+}
+
+impl MergeValue {
+    ///
+    /// Return the value as text, `None` for numbers and addresses
+    ///
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            MergeValue::Text(v)
+            | MergeValue::Date(v)
+            | MergeValue::Birthday(v)
+            | MergeValue::Phone(v)
+            | MergeValue::Url(v)
+            | MergeValue::Image(v)
+            | MergeValue::Choice(v) => Some(v.as_str()),
+            MergeValue::Number(_) | MergeValue::Address(_) => None,
+        }
+    }
+
+    ///
+    /// Return the numeric value. Numeric text is converted too.
+    ///
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            MergeValue::Number(n) => n.as_f64(),
+            _ => self.as_str().and_then(|v| v.trim().parse::<f64>().ok()),
+        }
+    }
+
+    ///
+    /// Return the address value
+    ///
+    pub fn as_address(&self) -> Option<&MergeAddress> {
+        match self {
+            MergeValue::Address(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Return true for empty text values
+    ///
+    pub fn is_empty(&self) -> bool {
+        match self.as_str() {
+            Some(v) => v.trim().is_empty(),
+            None => false,
+        }
+    }
+
+    ///
+    /// Convert the value into the variant that matches the type of the merge field
+    ///
+    pub fn typed(self, field: &ListMergeField) -> MergeValue {
+        let text = match self.as_str() {
+            Some(v) => v.to_string(),
+            None => return self,
+        };
+        match field.mf_type.as_str() {
+            "number" => match text.trim().parse::<Number>() {
+                Ok(n) => MergeValue::Number(n),
+                Err(_) => MergeValue::Text(text),
+            },
+            "date" => MergeValue::Date(text),
+            "birthday" => MergeValue::Birthday(text),
+            "phone" => MergeValue::Phone(text),
+            "url" => MergeValue::Url(text),
+            "imageurl" => MergeValue::Image(text),
+            "dropdown" | "radio" => MergeValue::Choice(text),
+            _ => MergeValue::Text(text),
+        }
+    }
+}
+
+impl fmt::Display for MergeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MergeValue::Number(n) => write!(f, "{}", n),
+            MergeValue::Address(a) => write!(
+                f,
+                "{} {} {} {} {} {}",
+                a.addr1, a.addr2, a.city, a.state, a.zip, a.country
+            ),
+            _ => write!(f, "{}", self.as_str().unwrap_or_default()),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MergeValue {
+    fn from(value: &'a str) -> Self {
+        MergeValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for MergeValue {
+    fn from(value: String) -> Self {
+        MergeValue::Text(value)
+    }
+}
+
+impl From<f64> for MergeValue {
+    /// NaN and infinite values can't be sent as JSON numbers, they become text
+    fn from(value: f64) -> Self {
+        match Number::from_f64(value) {
+            Some(n) => MergeValue::Number(n),
+            None => MergeValue::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<i64> for MergeValue {
+    fn from(value: i64) -> Self {
+        MergeValue::Number(Number::from(value))
+    }
+}
+
+impl From<MergeAddress> for MergeValue {
+    fn from(value: MergeAddress) -> Self {
+        MergeValue::Address(value)
+    }
+}
+
+///
+/// Problem found when validating the merge fields of a member
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeFieldError {
+    /// Merge tag, e.g. FNAME
+    pub tag: String,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl MergeFieldError {
+    fn new(tag: &str, message: &str) -> Self {
+        MergeFieldError {
+            tag: tag.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for MergeFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.tag, self.message)
+    }
+}
+
+///
+/// Validate the merge field values against the definitions of the list
+///
+/// Arguments:
+///     fields: Merge fields of the list, see `ListType::get_merge_fields`
+///     values: Values to send
+///     check_required: Report the required fields without value (new members)
+///
+pub fn validate_merge_values(
+    fields: &[ListMergeField],
+    values: &HashMap<String, MergeValue>,
+    check_required: bool,
+) -> Vec<MergeFieldError> {
+    let mut errors = Vec::new();
+
+    for tag in values.keys() {
+        if !fields.iter().any(|f| &f.tag == tag) {
+            errors.push(MergeFieldError::new(tag, "unknown merge field"));
+        }
+    }
+
+    for field in fields {
+        let value = match values.get(&field.tag) {
+            Some(v) if !v.is_empty() => v,
+            _ => {
+                if check_required && field.required && field.default_value.is_empty() {
+                    errors.push(MergeFieldError::new(&field.tag, "the field is required"));
+                }
+                continue;
+            }
+        };
+        if let Some(message) = check_value(field, value) {
+            errors.push(MergeFieldError::new(&field.tag, &message));
+        }
+    }
+
+    errors.sort_by(|a, b| a.tag.cmp(&b.tag));
+    errors
+}
+
+fn check_value(field: &ListMergeField, value: &MergeValue) -> Option<String> {
+    let options = &field.options;
+    match field.mf_type.as_str() {
+        "number" => {
+            if value.as_f64().is_none() {
+                return Some("expected a number".to_string());
+            }
+        }
+        "address" => match value.as_address() {
+            Some(a) => {
+                if a.addr1.is_empty() || a.city.is_empty() || a.state.is_empty() || a.zip.is_empty()
+                {
+                    return Some("the address needs addr1, city, state and zip".to_string());
+                }
+            }
+            None => return Some("expected an address".to_string()),
+        },
+        "date" => {
+            let format = if options.date_format.is_empty() {
+                "MM/DD/YYYY"
+            } else {
+                options.date_format.as_str()
+            };
+            let text = value.as_str().unwrap_or_default();
+            if !matches_date_format(text, format) && !matches_date_format(text, "YYYY-MM-DD") {
+                return Some(format!("expected a date with format {}", format));
+            }
+        }
+        "birthday" => {
+            let format = if options.date_format.is_empty() {
+                "MM/DD"
+            } else {
+                options.date_format.as_str()
+            };
+            if !matches_date_format(value.as_str().unwrap_or_default(), format) {
+                return Some(format!("expected a birthday with format {}", format));
+            }
+        }
+        "phone" => {
+            let text = value.as_str().unwrap_or_default();
+            let digits = text.chars().filter(|c| c.is_ascii_digit()).count();
+            if options.phone_format == "US" && !matches_date_format(text, "NNN-NNN-NNNN") {
+                return Some("expected a US phone number (NNN-NNN-NNNN)".to_string());
+            }
+            if digits == 0 {
+                return Some("expected a phone number".to_string());
+            }
+        }
+        "url" | "imageurl" => {
+            let text = value.as_str().unwrap_or_default();
+            if !text.starts_with("http://") && !text.starts_with("https://") {
+                return Some("expected an url starting with http:// or https://".to_string());
+            }
+        }
+        "dropdown" | "radio" => {
+            let text = value.as_str().unwrap_or_default();
+            if !options.choices.iter().any(|c| c == text) {
+                return Some(format!("'{}' is not one of the choices", text));
+            }
+        }
+        _ => {
+            if value.as_str().is_none() {
+                return Some("expected a text".to_string());
+            }
+        }
+    }
+    None
+}
+
+///
+/// Check a value against a pattern like MM/DD/YYYY, where the letters are
+/// digits and any other character must match literally
+///
+fn matches_date_format(value: &str, format: &str) -> bool {
+    let value = value.trim();
+    value.len() == format.len()
+        && value
+            .chars()
+            .zip(format.chars())
+            .all(|(v, f)| match f.to_ascii_uppercase() {
+                'M' | 'D' | 'Y' | 'N' => v.is_ascii_digit(),
+                _ => v == f,
+            })
+}
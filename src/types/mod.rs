@@ -2,21 +2,30 @@
 //! Mailchimp Types
 //!
 
+mod analytics;
 mod api_root;
 mod authorized_apps;
 mod automation_campaign;
 mod automation_email_queue;
 mod automation_subscriber;
 mod campaign;
+mod campaign_builder;
 mod campaign_content;
 mod campaign_feedback;
+mod campaign_schedule;
 mod campaign_send_checklist;
+mod campaign_variate;
+mod campaign_workflow;
 mod contact;
 mod conversation_messages;
 mod conversations;
+pub(crate) mod csv;
+mod data_subject;
 mod ecommerce;
 mod empty;
+mod fields;
 mod industry_stats;
+mod landing_pages;
 mod link;
 mod list;
 mod list_abuse_report;
@@ -28,6 +37,8 @@ mod list_interest_categories;
 mod list_interests;
 mod list_locations;
 mod list_member_activity;
+mod list_member_activity_feed;
+mod list_member_events;
 mod list_member_goals;
 mod list_member_notes;
 mod list_member_tags;
@@ -37,26 +48,45 @@ mod list_segment_members;
 mod list_segment_options;
 mod list_segments;
 mod list_signup_forms;
+mod list_tags;
 mod list_webhooks;
+mod marketing_permissions;
+mod merge_renderer;
+mod merge_tags;
+mod merge_values;
 mod ping;
 mod report;
+mod report_export;
+mod segment_builder;
+mod segment_evaluator;
+mod status;
+mod subscriber_hash;
+mod timestamp;
 mod workflow_email;
 
+pub use self::analytics::*;
 pub use self::api_root::*;
 pub use self::authorized_apps::{AuthorizedAppType, AuthorizedAppsType, CreatedAuthorizedAppType};
 pub use self::automation_campaign::*;
 pub use self::automation_email_queue::*;
 pub use self::automation_subscriber::*;
 pub use self::campaign::*;
+pub use self::campaign_builder::*;
 pub use self::campaign_content::*;
 pub use self::campaign_feedback::*;
+pub use self::campaign_schedule::*;
 pub use self::campaign_send_checklist::*;
+pub use self::campaign_variate::*;
+pub use self::campaign_workflow::*;
 pub use self::contact::ContactType;
 pub use self::conversation_messages::*;
 pub use self::conversations::*;
+pub use self::data_subject::*;
 pub use self::ecommerce::*;
 pub use self::empty::*;
+pub use self::fields::*;
 pub use self::industry_stats::*;
+pub use self::landing_pages::*;
 pub use self::link::LinkType;
 pub use self::list::*;
 pub use self::list_abuse_report::*;
@@ -68,6 +98,8 @@ pub use self::list_interest_categories::*;
 pub use self::list_interests::*;
 pub use self::list_locations::*;
 pub use self::list_member_activity::*;
+pub use self::list_member_activity_feed::*;
+pub use self::list_member_events::*;
 pub use self::list_member_goals::*;
 pub use self::list_member_notes::*;
 pub use self::list_member_tags::*;
@@ -77,7 +109,19 @@ pub use self::list_segment_members::*;
 pub use self::list_segment_options::*;
 pub use self::list_segments::*;
 pub use self::list_signup_forms::*;
+pub use self::list_tags::*;
 pub use self::list_webhooks::*;
+pub use self::marketing_permissions::*;
+pub use self::merge_renderer::*;
+pub use self::merge_tags::{html_to_text, lint_merge_tags, MergeTagIssue};
+pub use self::merge_values::*;
 pub use self::ping::*;
 pub use self::report::*;
+pub use self::report_export::*;
+pub use self::segment_builder::*;
+pub use self::segment_evaluator::*;
+pub use self::status::*;
+pub use self::subscriber_hash::*;
+#[cfg(feature = "chrono")]
+pub use self::timestamp::parse_timestamp;
 pub use self::workflow_email::*;
@@ -2,8 +2,13 @@ use super::campaign::CampaignDeliveryStatusType;
 use super::ecommerce::ECommerceReportType;
 use super::industry_stats::IndustryStatsType;
 use super::link::LinkType;
+use super::status::CampaignKind;
+#[cfg(feature = "chrono")]
+use super::timestamp::parse_timestamp;
 use crate::api::MailchimpApi;
 use crate::iter::{BuildIter, MailchimpCollection, ResourceFilter};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -260,7 +265,7 @@ impl Default for AbSplitStatsType {
 pub struct TimewarpStatsType {
     /// For campaigns sent with timewarp, the time zone group the member is apart of.
     #[serde(default)]
-    pub gmt_offset: u64,
+    pub gmt_offset: i64,
     /// The number of opens.
     #[serde(default)]
     pub opens: u64,
@@ -364,7 +369,7 @@ pub struct ReportType {
     pub campaign_title: String,
     /// The type of campaign (regular, plain-text, ab_split, rss, automation, variate, or auto).
     #[serde(default, rename = "type")]
-    pub report_type: String,
+    pub report_type: CampaignKind,
     /// The unique list id.
     #[serde(default)]
     pub list_id: String,
@@ -442,6 +447,16 @@ pub struct ReportType {
     pub _links: Vec<LinkType>,
 }
 
+impl ReportType {
+    ///
+    /// `send_time` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn send_time_utc(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.send_time)
+    }
+}
+
 ///
 /// Collection Reports
 ///
@@ -499,7 +514,7 @@ pub struct ReportsFilter {
     /// The campaign type
     /// Possible Values:
     ///     regular - plaintext - absplit - rss - variate
-    pub campaign_type: Option<String>,
+    pub campaign_type: Option<CampaignKind>,
     /// Restrict the response to campaigns sent before the set time.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
     pub before_send_time: Option<String>,
@@ -0,0 +1,326 @@
+//! Export of the campaign reports to spreadsheets, see `Reports::export_reports`
+//!
+//! Every report is flattened into a `ReportRow` with stable columns, written
+//! as CSV with `write_reports_csv` or as JSON Lines with `write_reports_jsonl`.
+//!
+//! The rates of the campaign and of the industry are fractions (0.25), the
+//! averages of the list are percentages (25.0). The rates of the rows are
+//! fractions and the deltas are percentage points.
+//!
+
+use super::csv::csv_field;
+use super::report::ReportType;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+
+///
+/// Difference of the rates of the campaign against the averages of the list
+/// and of the industry, in percentage points. `None` when the average is not known.
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ReportDeltas {
+    /// Open rate minus the average open rate of the list.
+    pub open_rate_vs_list: Option<f32>,
+    /// Click rate minus the average click rate of the list.
+    pub click_rate_vs_list: Option<f32>,
+    /// Open rate minus the open rate of the industry.
+    pub open_rate_vs_industry: Option<f32>,
+    /// Click rate minus the click rate of the industry.
+    pub click_rate_vs_industry: Option<f32>,
+    /// Bounce rate minus the bounce rate of the industry.
+    pub bounce_rate_vs_industry: Option<f32>,
+    /// Unsubscribe rate minus the unsubscribe rate of the industry.
+    pub unsub_rate_vs_industry: Option<f32>,
+}
+
+impl ReportDeltas {
+    ///
+    /// Deltas of the report
+    ///
+    pub fn from_report(report: &ReportType) -> Self {
+        let row = ReportRow::from_report(report, false);
+        let points = |rate: f32, average: f32| {
+            if average > 0.0 {
+                Some(round(rate * 100.0 - average))
+            } else {
+                None
+            }
+        };
+        let industry = &report.industry_stats;
+        ReportDeltas {
+            open_rate_vs_list: points(row.open_rate, report.list_stats.open_rate),
+            click_rate_vs_list: points(row.click_rate, report.list_stats.click_rate),
+            open_rate_vs_industry: points(row.open_rate, industry.open_rate * 100.0),
+            click_rate_vs_industry: points(row.click_rate, industry.click_rate * 100.0),
+            bounce_rate_vs_industry: points(row.bounce_rate, industry.bounce_rate * 100.0),
+            unsub_rate_vs_industry: points(row.unsub_rate, industry.unsub_rate * 100.0),
+        }
+    }
+}
+
+///
+/// A campaign report flattened into the columns of a spreadsheet
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ReportRow {
+    /// The campaign id.
+    pub id: String,
+    /// The title of the campaign.
+    pub campaign_title: String,
+    /// The type of campaign.
+    pub campaign_type: String,
+    /// The list id.
+    pub list_id: String,
+    /// The name of the list.
+    pub list_name: String,
+    /// The subject line of the campaign.
+    pub subject_line: String,
+    /// The date and time the campaign was sent in ISO 8601 format.
+    pub send_time: String,
+    /// The total number of emails sent.
+    pub emails_sent: u64,
+    /// The number of abuse reports.
+    pub abuse_reports: u64,
+    /// The number of unsubscribed members.
+    pub unsubscribed: u64,
+    /// Unsubscribed divided by the emails sent.
+    pub unsub_rate: f32,
+    /// The number of hard bounces.
+    pub hard_bounces: u64,
+    /// The number of soft bounces.
+    pub soft_bounces: u64,
+    /// The number of syntax-related bounces.
+    pub syntax_errors: u64,
+    /// Bounces divided by the emails sent.
+    pub bounce_rate: f32,
+    /// How many times the campaign has been forwarded.
+    pub forwards_count: u64,
+    /// How many times the forwarded campaign has been opened.
+    pub forwards_opens: u64,
+    /// The total number of opens.
+    pub opens_total: u64,
+    /// The number of unique opens.
+    pub unique_opens: u64,
+    /// Unique opens divided by the successful deliveries.
+    pub open_rate: f32,
+    /// The date and time of the last open.
+    pub last_open: String,
+    /// The total number of clicks.
+    pub clicks_total: u64,
+    /// The number of unique clicks.
+    pub unique_clicks: u64,
+    /// The number of subscribers who clicked.
+    pub unique_subscriber_clicks: u64,
+    /// Unique clicks divided by the successful deliveries.
+    pub click_rate: f32,
+    /// The date and time of the last click.
+    pub last_click: String,
+    /// The total orders of the campaign.
+    pub total_orders: u64,
+    /// The total spent of the campaign.
+    pub total_spent: f32,
+    /// The total revenue of the campaign.
+    pub total_revenue: f32,
+    /// The currency of the e-commerce stats.
+    pub currency_code: String,
+    /// The average open rate of the list, in percentage.
+    pub list_open_rate: f32,
+    /// The average click rate of the list, in percentage.
+    pub list_click_rate: f32,
+    /// The average number of subscriptions per month of the list.
+    pub list_sub_rate: f32,
+    /// The average number of unsubscriptions per month of the list.
+    pub list_unsub_rate: f32,
+    /// The industry of the account.
+    pub industry: String,
+    /// The open rate of the industry.
+    pub industry_open_rate: f32,
+    /// The click rate of the industry.
+    pub industry_click_rate: f32,
+    /// The bounce rate of the industry.
+    pub industry_bounce_rate: f32,
+    /// The unsubscribe rate of the industry.
+    pub industry_unsub_rate: f32,
+    /// The abuse rate of the industry.
+    pub industry_abuse_rate: f32,
+    /// The deltas against the list and the industry, when requested.
+    #[serde(default, flatten, skip_serializing_if = "Option::is_none")]
+    pub deltas: Option<ReportDeltas>,
+}
+
+impl ReportRow {
+    ///
+    /// Flatten the report
+    ///
+    /// Arguments:
+    ///     report: The campaign report
+    ///     deltas: Compute the deltas against the list and the industry
+    ///
+    pub fn from_report(report: &ReportType, deltas: bool) -> Self {
+        let rate = |count: u64| {
+            if report.emails_sent > 0 {
+                round(count as f32 / report.emails_sent as f32)
+            } else {
+                0.0
+            }
+        };
+        let bounces = &report.bounces;
+        ReportRow {
+            id: report.id.clone(),
+            campaign_title: report.campaign_title.clone(),
+            campaign_type: report.report_type.to_string(),
+            list_id: report.list_id.clone(),
+            list_name: report.list_name.clone(),
+            subject_line: report.subject_line.clone(),
+            send_time: report.send_time.clone(),
+            emails_sent: report.emails_sent,
+            abuse_reports: report.abuse_reports,
+            unsubscribed: report.unsubscribed,
+            unsub_rate: rate(report.unsubscribed),
+            hard_bounces: bounces.hard_bounces,
+            soft_bounces: bounces.soft_bounces,
+            syntax_errors: bounces.syntax_errors,
+            bounce_rate: rate(bounces.hard_bounces + bounces.soft_bounces),
+            forwards_count: report.forwards.forwards_count,
+            forwards_opens: report.forwards.forwards_opens,
+            opens_total: report.opens.opens_total,
+            unique_opens: report.opens.unique_opens,
+            open_rate: report.opens.open_rate,
+            last_open: report.opens.last_open.clone(),
+            clicks_total: report.clicks.clicks_total,
+            unique_clicks: report.clicks.unique_clicks,
+            unique_subscriber_clicks: report.clicks.unique_subscriber_clicks,
+            click_rate: report.clicks.click_rate,
+            last_click: report.clicks.last_click.clone(),
+            total_orders: report.ecommerce.total_orders,
+            total_spent: report.ecommerce.total_spent,
+            total_revenue: report.ecommerce.total_revenue,
+            currency_code: report.ecommerce.currency_code.clone(),
+            list_open_rate: report.list_stats.open_rate,
+            list_click_rate: report.list_stats.click_rate,
+            list_sub_rate: report.list_stats.sub_rate,
+            list_unsub_rate: report.list_stats.unsub_rate,
+            industry: report.industry_stats.industry_stats_type.clone(),
+            industry_open_rate: report.industry_stats.open_rate,
+            industry_click_rate: report.industry_stats.click_rate,
+            industry_bounce_rate: report.industry_stats.bounce_rate,
+            industry_unsub_rate: report.industry_stats.unsub_rate,
+            industry_abuse_rate: report.industry_stats.abuse_rate,
+            deltas: if deltas {
+                Some(ReportDeltas::from_report(report))
+            } else {
+                None
+            },
+        }
+    }
+
+    ///
+    /// Names and values of the columns, in order
+    ///
+    pub fn columns(&self) -> Vec<(&'static str, String)> {
+        let mut columns = vec![
+            ("id", self.id.clone()),
+            ("campaign_title", self.campaign_title.clone()),
+            ("campaign_type", self.campaign_type.clone()),
+            ("list_id", self.list_id.clone()),
+            ("list_name", self.list_name.clone()),
+            ("subject_line", self.subject_line.clone()),
+            ("send_time", self.send_time.clone()),
+            ("emails_sent", self.emails_sent.to_string()),
+            ("abuse_reports", self.abuse_reports.to_string()),
+            ("unsubscribed", self.unsubscribed.to_string()),
+            ("unsub_rate", self.unsub_rate.to_string()),
+            ("hard_bounces", self.hard_bounces.to_string()),
+            ("soft_bounces", self.soft_bounces.to_string()),
+            ("syntax_errors", self.syntax_errors.to_string()),
+            ("bounce_rate", self.bounce_rate.to_string()),
+            ("forwards_count", self.forwards_count.to_string()),
+            ("forwards_opens", self.forwards_opens.to_string()),
+            ("opens_total", self.opens_total.to_string()),
+            ("unique_opens", self.unique_opens.to_string()),
+            ("open_rate", self.open_rate.to_string()),
+            ("last_open", self.last_open.clone()),
+            ("clicks_total", self.clicks_total.to_string()),
+            ("unique_clicks", self.unique_clicks.to_string()),
+            (
+                "unique_subscriber_clicks",
+                self.unique_subscriber_clicks.to_string(),
+            ),
+            ("click_rate", self.click_rate.to_string()),
+            ("last_click", self.last_click.clone()),
+            ("total_orders", self.total_orders.to_string()),
+            ("total_spent", self.total_spent.to_string()),
+            ("total_revenue", self.total_revenue.to_string()),
+            ("currency_code", self.currency_code.clone()),
+            ("list_open_rate", self.list_open_rate.to_string()),
+            ("list_click_rate", self.list_click_rate.to_string()),
+            ("list_sub_rate", self.list_sub_rate.to_string()),
+            ("list_unsub_rate", self.list_unsub_rate.to_string()),
+            ("industry", self.industry.clone()),
+            ("industry_open_rate", self.industry_open_rate.to_string()),
+            ("industry_click_rate", self.industry_click_rate.to_string()),
+            (
+                "industry_bounce_rate",
+                self.industry_bounce_rate.to_string(),
+            ),
+            ("industry_unsub_rate", self.industry_unsub_rate.to_string()),
+            ("industry_abuse_rate", self.industry_abuse_rate.to_string()),
+        ];
+        if let Some(d) = &self.deltas {
+            let value = |v: Option<f32>| v.map(|v| v.to_string()).unwrap_or_default();
+            columns.extend(vec![
+                ("open_rate_vs_list", value(d.open_rate_vs_list)),
+                ("click_rate_vs_list", value(d.click_rate_vs_list)),
+                ("open_rate_vs_industry", value(d.open_rate_vs_industry)),
+                ("click_rate_vs_industry", value(d.click_rate_vs_industry)),
+                ("bounce_rate_vs_industry", value(d.bounce_rate_vs_industry)),
+                ("unsub_rate_vs_industry", value(d.unsub_rate_vs_industry)),
+            ]);
+        }
+        columns
+    }
+}
+
+impl From<&ReportType> for ReportRow {
+    fn from(report: &ReportType) -> Self {
+        ReportRow::from_report(report, false)
+    }
+}
+
+/// Round to 4 decimals, the rates are kept as `f32`
+fn round(value: f32) -> f32 {
+    (value * 10_000.0).round() / 10_000.0
+}
+
+///
+/// Write the rows as CSV with a header. The delta columns are written when
+/// the first row has them.
+///
+pub fn write_reports_csv<W: Write>(rows: &[ReportRow], mut writer: W) -> io::Result<()> {
+    let header = rows
+        .first()
+        .cloned()
+        .unwrap_or_default()
+        .columns()
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<&str>>()
+        .join(",");
+    writeln!(writer, "{}", header)?;
+    for row in rows {
+        let values: Vec<String> = row.columns().iter().map(|(_, v)| csv_field(v)).collect();
+        writeln!(writer, "{}", values.join(","))?;
+    }
+    Ok(())
+}
+
+///
+/// Write the rows as JSON Lines, one JSON object per line
+///
+pub fn write_reports_jsonl<W: Write>(rows: &[ReportRow], mut writer: W) -> io::Result<()> {
+    for row in rows {
+        serde_json::to_writer(&mut writer, row)?;
+        writeln!(writer)?;
+    }
+    Ok(())
+}
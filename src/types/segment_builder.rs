@@ -0,0 +1,635 @@
+//! Implement a typed builder for the segment conditions
+//!
+//! Each condition type has its own builder that only exposes the operators the
+//! API accepts for it, so the result is always a valid `SegmentOptionsType`.
+//!
+//! ```
+//!     use mailchimp::types::{Date, Segment, SegmentOptionsType};
+//!
+//!     let options: SegmentOptionsType = Segment::all()
+//!         .merge("FNAME")
+//!         .is("Ana")
+//!         .and(Date::timestamp_opt().within_days(30))
+//!         .into();
+//!     assert_eq!(options.match_filter, "all");
+//!     assert_eq!(options.conditions.len(), 2);
+//! ```
+//!
+
+use super::list_segment_options::{
+    SegmentConditionFlag, SegmentConditionInner, SegmentConditionOp, SegmentConditionsType,
+    SegmentOptionsType, SegmentValue,
+};
+use crate::internal::error_type::MailchimpErrorType;
+
+fn inner<V: Into<SegmentValue>>(
+    field: &str,
+    op: SegmentConditionOp,
+    value: V,
+    extra: Option<String>,
+) -> SegmentConditionInner {
+    SegmentConditionInner {
+        field: field.to_string(),
+        op,
+        value: value.into(),
+        extra,
+    }
+}
+
+///
+/// Segment
+///
+/// Conditions of a segment and how they are matched
+///
+#[derive(Debug, Clone)]
+pub struct Segment {
+    match_filter: &'static str,
+    conditions: Vec<SegmentConditionsType>,
+}
+
+impl Segment {
+    ///
+    /// Members must match all the conditions
+    ///
+    pub fn all() -> Self {
+        Segment {
+            match_filter: "all",
+            conditions: Vec::new(),
+        }
+    }
+
+    ///
+    /// Members must match at least one of the conditions
+    ///
+    pub fn any() -> Self {
+        Segment {
+            match_filter: "any",
+            conditions: Vec::new(),
+        }
+    }
+
+    ///
+    /// Add a condition. It is combined with the others according to the
+    /// match type of the segment.
+    ///
+    pub fn and<C: Into<SegmentConditionsType>>(mut self, condition: C) -> Self {
+        self.conditions.push(condition.into());
+        self
+    }
+
+    ///
+    /// Start a condition on a text or number merge field, e.g. `FNAME`
+    ///
+    pub fn merge(self, tag: &str) -> SegmentMerge {
+        SegmentMerge {
+            segment: self,
+            merge: Merge::text(tag),
+        }
+    }
+
+    ///
+    /// Conditions added so far
+    ///
+    pub fn conditions(&self) -> &[SegmentConditionsType] {
+        &self.conditions
+    }
+
+    ///
+    /// Segment options to send to the API
+    ///
+    pub fn build(self) -> SegmentOptionsType {
+        SegmentOptionsType {
+            match_filter: self.match_filter.to_string(),
+            conditions: self.conditions,
+            ..SegmentOptionsType::default()
+        }
+    }
+}
+
+impl From<Segment> for SegmentOptionsType {
+    fn from(segment: Segment) -> Self {
+        segment.build()
+    }
+}
+
+///
+/// Text Merge Field condition
+///
+#[derive(Debug, Clone)]
+pub struct Merge {
+    field: String,
+}
+
+///
+/// Dropdown/Radio Merge Field condition
+///
+#[derive(Debug, Clone)]
+pub struct SelectMerge {
+    field: String,
+}
+
+///
+/// Text Merge Field condition started from `Segment::merge`, the operator
+/// adds it to the segment
+///
+#[derive(Debug, Clone)]
+pub struct SegmentMerge {
+    segment: Segment,
+    merge: Merge,
+}
+
+macro_rules! text_ops {
+    ($(#[$doc:meta] $name:ident => $op:ident,)*) => {
+        impl Merge {
+            $(
+                #[$doc]
+                pub fn $name<V: Into<SegmentValue>>(&self, value: V) -> SegmentConditionsType {
+                    self.condition(SegmentConditionOp::$op, value.into())
+                }
+            )*
+        }
+
+        impl SegmentMerge {
+            $(
+                #[$doc]
+                pub fn $name<V: Into<SegmentValue>>(self, value: V) -> Segment {
+                    let condition = self.merge.$name(value);
+                    self.segment.and(condition)
+                }
+            )*
+        }
+    };
+}
+
+text_ops! {
+    /// The field is exactly the value
+    is => Is,
+    /// The field is not the value
+    not => Not,
+    /// The field contains the value
+    contains => Contains,
+    /// The field does not contain the value
+    notcontain => Notcontain,
+    /// The field starts with the value
+    starts => Starts,
+    /// The field ends with the value
+    ends => Ends,
+    /// The field is greater than the value
+    greater => Greater,
+    /// The field is less than the value
+    less => Less,
+}
+
+impl Merge {
+    ///
+    /// Condition on a text or number merge field, e.g. `FNAME`
+    ///
+    pub fn text(tag: &str) -> Self {
+        Merge {
+            field: tag.to_string(),
+        }
+    }
+
+    ///
+    /// Condition on a dropdown or radio merge field
+    ///
+    pub fn select(tag: &str) -> SelectMerge {
+        SelectMerge {
+            field: tag.to_string(),
+        }
+    }
+
+    ///
+    /// The field is blank
+    ///
+    pub fn blank(&self) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::Blank, SegmentValue::default())
+    }
+
+    ///
+    /// The field is not blank
+    ///
+    pub fn blank_not(&self) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::BlankNot, SegmentValue::default())
+    }
+
+    fn condition(&self, op: SegmentConditionOp, value: SegmentValue) -> SegmentConditionsType {
+        SegmentConditionsType::TextMerge(inner(&self.field, op, value, None))
+    }
+}
+
+impl SegmentMerge {
+    ///
+    /// The field is blank
+    ///
+    pub fn blank(self) -> Segment {
+        let condition = self.merge.blank();
+        self.segment.and(condition)
+    }
+
+    ///
+    /// The field is not blank
+    ///
+    pub fn blank_not(self) -> Segment {
+        let condition = self.merge.blank_not();
+        self.segment.and(condition)
+    }
+}
+
+impl SelectMerge {
+    ///
+    /// The field is the choice
+    ///
+    pub fn is(&self, choice: &str) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::Is, choice)
+    }
+
+    ///
+    /// The field is not the choice
+    ///
+    pub fn not(&self, choice: &str) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::Not, choice)
+    }
+
+    ///
+    /// The field is blank
+    ///
+    pub fn blank(&self) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::Blank, "")
+    }
+
+    ///
+    /// The field is not blank
+    ///
+    pub fn blank_not(&self) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::BlankNot, "")
+    }
+
+    fn condition(&self, op: SegmentConditionOp, value: &str) -> SegmentConditionsType {
+        SegmentConditionsType::SelectMerge(inner(&self.field, op, value, None))
+    }
+}
+
+///
+/// Date condition on the opt-in time or the last change of the member
+///
+#[derive(Debug, Clone)]
+pub struct Date {
+    field: &'static str,
+}
+
+impl Date {
+    ///
+    /// Date the member opted in
+    ///
+    pub fn timestamp_opt() -> Self {
+        Date {
+            field: "timestamp_opt",
+        }
+    }
+
+    ///
+    /// Date the member info was last changed
+    ///
+    pub fn info_changed() -> Self {
+        Date {
+            field: "info_changed",
+        }
+    }
+
+    ///
+    /// The date is the given day (YYYY-MM-DD)
+    ///
+    pub fn is(&self, date: &str) -> SegmentConditionsType {
+        self.on_date(SegmentConditionOp::Is, date)
+    }
+
+    ///
+    /// The date is not the given day (YYYY-MM-DD)
+    ///
+    pub fn not(&self, date: &str) -> SegmentConditionsType {
+        self.on_date(SegmentConditionOp::Not, date)
+    }
+
+    ///
+    /// The date is after the given day (YYYY-MM-DD)
+    ///
+    pub fn after(&self, date: &str) -> SegmentConditionsType {
+        self.on_date(SegmentConditionOp::Greater, date)
+    }
+
+    ///
+    /// The date is before the given day (YYYY-MM-DD)
+    ///
+    pub fn before(&self, date: &str) -> SegmentConditionsType {
+        self.on_date(SegmentConditionOp::Less, date)
+    }
+
+    ///
+    /// The date is within the last `days` days
+    ///
+    pub fn within_days(&self, days: u32) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::Within, i64::from(days), None)
+    }
+
+    ///
+    /// The date is not within the last `days` days
+    ///
+    pub fn notwithin_days(&self, days: u32) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::Notwithin, i64::from(days), None)
+    }
+
+    fn on_date(&self, op: SegmentConditionOp, date: &str) -> SegmentConditionsType {
+        self.condition(op, "date", Some(date.to_string()))
+    }
+
+    fn condition<V: Into<SegmentValue>>(
+        &self,
+        op: SegmentConditionOp,
+        value: V,
+        extra: Option<String>,
+    ) -> SegmentConditionsType {
+        SegmentConditionsType::Date(inner(self.field, op, value, extra))
+    }
+}
+
+///
+/// Member Rating condition. The rating goes from 1 to 5 stars, any other value
+/// is an error.
+///
+#[derive(Debug, Clone)]
+pub struct MemberRating;
+
+impl MemberRating {
+    ///
+    /// The rating is exactly `stars`
+    ///
+    pub fn is(stars: u8) -> Result<SegmentConditionsType, MailchimpErrorType> {
+        Self::condition(SegmentConditionOp::Is, stars)
+    }
+
+    ///
+    /// The rating is not `stars`
+    ///
+    pub fn not(stars: u8) -> Result<SegmentConditionsType, MailchimpErrorType> {
+        Self::condition(SegmentConditionOp::Not, stars)
+    }
+
+    ///
+    /// The rating is greater than `stars`
+    ///
+    pub fn greater(stars: u8) -> Result<SegmentConditionsType, MailchimpErrorType> {
+        Self::condition(SegmentConditionOp::Greater, stars)
+    }
+
+    ///
+    /// The rating is less than `stars`
+    ///
+    pub fn less(stars: u8) -> Result<SegmentConditionsType, MailchimpErrorType> {
+        Self::condition(SegmentConditionOp::Less, stars)
+    }
+
+    fn condition(
+        op: SegmentConditionOp,
+        stars: u8,
+    ) -> Result<SegmentConditionsType, MailchimpErrorType> {
+        if !(1..=5).contains(&stars) {
+            return Err(MailchimpErrorType::invalid_request(&format!(
+                "the member rating goes from 1 to 5 stars, got {}",
+                stars
+            )));
+        }
+        Ok(SegmentConditionsType::MemberRating(inner(
+            "rating",
+            op,
+            i64::from(stars),
+            None,
+        )))
+    }
+}
+
+///
+/// Language condition, the value is a language code like `en` or `es`
+///
+#[derive(Debug, Clone)]
+pub struct Language;
+
+impl Language {
+    ///
+    /// The language of the member is `code`
+    ///
+    pub fn is(code: &str) -> SegmentConditionsType {
+        SegmentConditionsType::Language(inner("language", SegmentConditionOp::Is, code, None))
+    }
+
+    ///
+    /// The language of the member is not `code`
+    ///
+    pub fn not(code: &str) -> SegmentConditionsType {
+        SegmentConditionsType::Language(inner("language", SegmentConditionOp::Not, code, None))
+    }
+}
+
+///
+/// VIP condition
+///
+#[derive(Debug, Clone)]
+pub struct Vip;
+
+impl Vip {
+    ///
+    /// The member is a VIP
+    ///
+    pub fn member() -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Member)
+    }
+
+    ///
+    /// The member is not a VIP
+    ///
+    pub fn notmember() -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Notmember)
+    }
+
+    fn condition(op: SegmentConditionOp) -> SegmentConditionsType {
+        SegmentConditionsType::VIP(SegmentConditionFlag {
+            field: "gmonkey".to_string(),
+            op,
+        })
+    }
+}
+
+///
+/// Interests condition on the interests of one category
+///
+#[derive(Debug, Clone)]
+pub struct Interests {
+    field: String,
+}
+
+impl Interests {
+    ///
+    /// Interests of the category `category_id`
+    ///
+    pub fn category(category_id: &str) -> Self {
+        Interests {
+            field: format!("interests-{}", category_id),
+        }
+    }
+
+    ///
+    /// The member has at least one of the interests
+    ///
+    pub fn contains_any(&self, interest_ids: &[&str]) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::Interestcontains, interest_ids)
+    }
+
+    ///
+    /// The member has all the interests
+    ///
+    pub fn contains_all(&self, interest_ids: &[&str]) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::Interestcontainsall, interest_ids)
+    }
+
+    ///
+    /// The member has none of the interests
+    ///
+    pub fn contains_none(&self, interest_ids: &[&str]) -> SegmentConditionsType {
+        self.condition(SegmentConditionOp::Interestnotcontains, interest_ids)
+    }
+
+    fn condition(&self, op: SegmentConditionOp, interest_ids: &[&str]) -> SegmentConditionsType {
+        let ids: Vec<String> = interest_ids.iter().map(|i| i.to_string()).collect();
+        SegmentConditionsType::Interests(inner(&self.field, op, ids, None))
+    }
+}
+
+///
+/// Static Segment (tag) condition
+///
+#[derive(Debug, Clone)]
+pub struct StaticSegment;
+
+impl StaticSegment {
+    ///
+    /// The member has the tag / belongs to the static segment
+    ///
+    pub fn member_of(segment_id: u64) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::StaticIs, segment_id)
+    }
+
+    ///
+    /// The member doesn't have the tag / doesn't belong to the static segment
+    ///
+    pub fn not_member_of(segment_id: u64) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::StaticNot, segment_id)
+    }
+
+    fn condition(op: SegmentConditionOp, segment_id: u64) -> SegmentConditionsType {
+        SegmentConditionsType::StaticSegment(inner("static_segment", op, segment_id, None))
+    }
+}
+
+///
+/// Email Address condition
+///
+#[derive(Debug, Clone)]
+pub struct EmailAddress;
+
+impl EmailAddress {
+    ///
+    /// The address is exactly the value
+    ///
+    pub fn is(value: &str) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Is, value)
+    }
+
+    ///
+    /// The address is not the value
+    ///
+    pub fn not(value: &str) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Not, value)
+    }
+
+    ///
+    /// The address contains the value
+    ///
+    pub fn contains(value: &str) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Contains, value)
+    }
+
+    ///
+    /// The address does not contain the value
+    ///
+    pub fn notcontain(value: &str) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Notcontain, value)
+    }
+
+    ///
+    /// The address starts with the value
+    ///
+    pub fn starts(value: &str) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Starts, value)
+    }
+
+    ///
+    /// The address ends with the value, e.g. the domain
+    ///
+    pub fn ends(value: &str) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Ends, value)
+    }
+
+    fn condition(op: SegmentConditionOp, value: &str) -> SegmentConditionsType {
+        SegmentConditionsType::EmailAddress(inner("EMAIL", op, value, None))
+    }
+}
+
+///
+/// Location condition on the country or state detected from the IP address
+///
+#[derive(Debug, Clone)]
+pub struct Location;
+
+impl Location {
+    ///
+    /// The member is in the country (two-letter code)
+    ///
+    pub fn country(code: &str) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Ipgeocountry, code)
+    }
+
+    ///
+    /// The member is not in the country (two-letter code)
+    ///
+    pub fn not_country(code: &str) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Ipgeonotcountry, code)
+    }
+
+    ///
+    /// The member is in the state (region code)
+    ///
+    pub fn state(code: &str) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Ipgeostate, code)
+    }
+
+    ///
+    /// The member is not in the state (region code)
+    ///
+    pub fn not_state(code: &str) -> SegmentConditionsType {
+        Self::condition(SegmentConditionOp::Ipgeonotstate, code)
+    }
+
+    ///
+    /// The location of the member is unknown
+    ///
+    pub fn unknown() -> SegmentConditionsType {
+        SegmentConditionsType::IPGeoUnknown(SegmentConditionFlag {
+            field: "ipgeo".to_string(),
+            op: SegmentConditionOp::Ipgeounknown,
+        })
+    }
+
+    fn condition(op: SegmentConditionOp, code: &str) -> SegmentConditionsType {
+        SegmentConditionsType::IPGeoCountryState(inner("ipgeo", op, code, None))
+    }
+}
@@ -0,0 +1,379 @@
+//! Implement a local evaluator for the segment conditions
+//!
+//! It previews which members match a `SegmentOptionsType` without calling
+//! Mailchimp. Conditions that depend on data the member resource doesn't
+//! carry (campaign activity, ecommerce, social profiles...) are reported as
+//! unsupported. A member whose result depends on them is neither matching nor
+//! excluded, it's reported as undetermined.
+//!
+
+use super::list_members::ListMember;
+use super::list_segment_options::{
+    SegmentConditionOp, SegmentConditionsType, SegmentOptionsType, SegmentValue,
+};
+use super::timestamp::{days_from_civil, days_in_month};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+///
+/// Condition that couldn't be evaluated locally
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsupportedCondition {
+    /// Position of the condition in `SegmentOptionsType::conditions`
+    pub index: usize,
+    /// Condition type, e.g. `Aim`
+    pub condition_type: String,
+    /// Field of the condition
+    pub field: String,
+    /// Why it couldn't be evaluated
+    pub reason: String,
+}
+
+///
+/// Result of evaluating a segment against a set of members
+///
+#[derive(Debug, Clone)]
+pub struct SegmentPreview<'a> {
+    /// Members that match the segment
+    pub matching: Vec<&'a ListMember>,
+    /// Members that may match, depending on the unsupported conditions
+    pub undetermined: Vec<&'a ListMember>,
+    /// Conditions that couldn't be evaluated
+    pub unsupported: Vec<UnsupportedCondition>,
+}
+
+impl<'a> SegmentPreview<'a> {
+    ///
+    /// Return true when every condition could be evaluated
+    ///
+    pub fn is_exact(&self) -> bool {
+        self.unsupported.is_empty()
+    }
+
+    ///
+    /// Email addresses of the matching members
+    ///
+    pub fn emails(&self) -> Vec<&'a str> {
+        self.matching
+            .iter()
+            .map(|m| m.email_address.as_str())
+            .collect()
+    }
+
+    ///
+    /// Email addresses of the members that may match
+    ///
+    pub fn undetermined_emails(&self) -> Vec<&'a str> {
+        self.undetermined
+            .iter()
+            .map(|m| m.email_address.as_str())
+            .collect()
+    }
+}
+
+///
+/// Segment Evaluator
+///
+/// Like Mailchimp, only subscribed members can match a segment unless
+/// `include_all_statuses` is used. The relative date conditions ("within the
+/// last N days") are evaluated against the current day or the day given to
+/// `SegmentEvaluator::at`.
+///
+#[derive(Debug, Clone)]
+pub struct SegmentEvaluator {
+    today: i64,
+    subscribed_only: bool,
+}
+
+impl Default for SegmentEvaluator {
+    fn default() -> Self {
+        SegmentEvaluator::new()
+    }
+}
+
+impl SegmentEvaluator {
+    ///
+    /// Evaluator that uses the current day
+    ///
+    pub fn new() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        SegmentEvaluator {
+            today: (secs / 86_400) as i64,
+            subscribed_only: true,
+        }
+    }
+
+    ///
+    /// Evaluator that uses a fixed day (YYYY-MM-DD), `None` if the date is invalid
+    ///
+    pub fn at(today: &str) -> Option<Self> {
+        Some(SegmentEvaluator {
+            today: parse_day(today)?,
+            subscribed_only: true,
+        })
+    }
+
+    ///
+    /// Let members with any status match
+    ///
+    pub fn include_all_statuses(mut self) -> Self {
+        self.subscribed_only = false;
+        self
+    }
+
+    ///
+    /// Evaluate the segment against the members. With match "all" a member is
+    /// undetermined when no supported condition fails and some are unsupported,
+    /// with match "any" when no supported condition matches and some are
+    /// unsupported.
+    ///
+    pub fn preview<'a>(
+        &self,
+        options: &SegmentOptionsType,
+        members: &'a [ListMember],
+    ) -> SegmentPreview<'a> {
+        let mut unsupported: Vec<UnsupportedCondition> = Vec::new();
+        let mut matching = Vec::new();
+        let mut undetermined = Vec::new();
+
+        for member in members {
+            let mut results = Vec::new();
+            let mut unknown = false;
+            for (index, condition) in options.conditions.iter().enumerate() {
+                match self.check(condition, member) {
+                    Ok(result) => results.push(result),
+                    Err(reason) => {
+                        unknown = true;
+                        if !unsupported.iter().any(|u| u.index == index) {
+                            unsupported.push(UnsupportedCondition {
+                                index,
+                                condition_type: condition.condition_type().to_string(),
+                                field: condition.field().to_string(),
+                                reason,
+                            });
+                        }
+                    }
+                }
+            }
+            if self.subscribed_only && !member.status.is_subscribed() {
+                continue;
+            }
+            // A supported condition that matches ("any") or fails ("all")
+            // decides the result whatever the unsupported ones
+            let any = options.match_filter == "any";
+            let decided = results.contains(&any);
+            if decided {
+                if any {
+                    matching.push(member);
+                }
+            } else if unknown {
+                undetermined.push(member);
+            } else if !any {
+                matching.push(member);
+            }
+        }
+
+        unsupported.sort_by_key(|u| u.index);
+        SegmentPreview {
+            matching,
+            undetermined,
+            unsupported,
+        }
+    }
+
+    ///
+    /// Evaluate a single condition, `Err` with the reason when it's not supported
+    ///
+    pub fn check(
+        &self,
+        condition: &SegmentConditionsType,
+        member: &ListMember,
+    ) -> Result<bool, String> {
+        let op = condition.op();
+        let value = condition.inner().map(|c| &c.value);
+        let extra = condition.inner().and_then(|c| c.extra.as_ref());
+        match condition {
+            SegmentConditionsType::TextMerge(c) | SegmentConditionsType::SelectMerge(c) => {
+                let text = match member.merge_fields.get(&c.field) {
+                    Some(v) => v.to_string(),
+                    None if c.field.starts_with("merge") => {
+                        return Err("merge fields must be referenced by their tag".to_string())
+                    }
+                    None => String::new(),
+                };
+                compare_text(&text, op, &c.value)
+            }
+            SegmentConditionsType::EmailAddress(c) => {
+                compare_text(&member.email_address, op, &c.value)
+            }
+            SegmentConditionsType::Interests(c) => {
+                let ids = c.value.as_list();
+                let has = |id: &String| member.interests.get(id).cloned().unwrap_or(false);
+                match op {
+                    SegmentConditionOp::Interestcontains => Ok(ids.iter().any(has)),
+                    SegmentConditionOp::Interestcontainsall => Ok(ids.iter().all(has)),
+                    SegmentConditionOp::Interestnotcontains => Ok(!ids.iter().any(has)),
+                    _ => Err(unsupported_op(op)),
+                }
+            }
+            SegmentConditionsType::MemberRating(c) => {
+                let rating = member.member_rating as f64;
+                let stars = c.value.as_f64().ok_or("the rating is not a number")?;
+                compare_number(rating, op, stars)
+            }
+            SegmentConditionsType::Language(c) => {
+                let code = c.value.as_text().unwrap_or_default();
+                match op {
+                    SegmentConditionOp::Is => Ok(member.language.eq_ignore_ascii_case(&code)),
+                    SegmentConditionOp::Not => Ok(!member.language.eq_ignore_ascii_case(&code)),
+                    _ => Err(unsupported_op(op)),
+                }
+            }
+            SegmentConditionsType::VIP(_) => match op {
+                SegmentConditionOp::Member => Ok(member.vip),
+                SegmentConditionOp::Notmember => Ok(!member.vip),
+                _ => Err(unsupported_op(op)),
+            },
+            SegmentConditionsType::IPGeoCountryState(c) => {
+                let code = c.value.as_text().unwrap_or_default();
+                let country = &member.location.country_code;
+                match op {
+                    SegmentConditionOp::Ipgeocountry => Ok(country.eq_ignore_ascii_case(&code)),
+                    SegmentConditionOp::Ipgeonotcountry => Ok(!country.eq_ignore_ascii_case(&code)),
+                    _ => Err("the member location has no state".to_string()),
+                }
+            }
+            SegmentConditionsType::IPGeoUnknown(_) => Ok(member.location.country_code.is_empty()
+                && member.location.latitude == 0.0
+                && member.location.longitude == 0.0),
+            SegmentConditionsType::StaticSegment(c) => {
+                let id = c.value.as_f64().ok_or("the segment id is not a number")? as u64;
+                let tagged = member.tags.iter().any(|t| t.id == id);
+                match op {
+                    SegmentConditionOp::StaticIs => Ok(tagged),
+                    SegmentConditionOp::StaticNot => Ok(!tagged),
+                    _ => Err(unsupported_op(op)),
+                }
+            }
+            SegmentConditionsType::Date(c) => {
+                let day = match c.field.as_str() {
+                    "timestamp_opt" => parse_day(&member.timestamp_opt),
+                    "info_changed" => parse_day(&member.last_changed),
+                    _ => return Err(format!("date field `{}` not supported", c.field)),
+                };
+                self.compare_day(day, op, value, extra)
+            }
+            SegmentConditionsType::NewSubsribers(c) => {
+                let days = c.value.as_f64().ok_or("the period is not a number")? as i64;
+                let day = parse_day(&member.timestamp_opt)
+                    .or_else(|| parse_day(&member.timestamp_signup));
+                match op {
+                    SegmentConditionOp::DateWithin => {
+                        Ok(day.map(|d| self.today - d <= days).unwrap_or(false))
+                    }
+                    _ => Err(unsupported_op(op)),
+                }
+            }
+            _ => Err("the condition type can't be evaluated locally".to_string()),
+        }
+    }
+
+    fn compare_day(
+        &self,
+        day: Option<i64>,
+        op: SegmentConditionOp,
+        value: Option<&SegmentValue>,
+        extra: Option<&String>,
+    ) -> Result<bool, String> {
+        let value = value.cloned().unwrap_or_default();
+        let day = match day {
+            Some(d) => d,
+            None => return Ok(false),
+        };
+        match op {
+            SegmentConditionOp::Within | SegmentConditionOp::Notwithin => {
+                let days = value.as_f64().ok_or("the period is not a number")? as i64;
+                let within = self.today - day <= days;
+                Ok(within == (op == SegmentConditionOp::Within))
+            }
+            SegmentConditionOp::Is
+            | SegmentConditionOp::Not
+            | SegmentConditionOp::Greater
+            | SegmentConditionOp::Less => {
+                let text = value.as_text().unwrap_or_default();
+                let date = if text == "date" {
+                    extra.cloned().unwrap_or_default()
+                } else {
+                    text
+                };
+                let target = parse_day(&date)
+                    .ok_or_else(|| format!("`{}` can't be evaluated locally", date))?;
+                compare_number(day as f64, op, target as f64)
+            }
+            _ => Err(unsupported_op(op)),
+        }
+    }
+}
+
+impl SegmentOptionsType {
+    ///
+    /// Preview which members match the segment, see `SegmentEvaluator`
+    ///
+    pub fn preview<'a>(&self, members: &'a [ListMember]) -> SegmentPreview<'a> {
+        SegmentEvaluator::new().preview(self, members)
+    }
+}
+
+fn unsupported_op(op: SegmentConditionOp) -> String {
+    format!("operator {:?} not supported for this condition", op)
+}
+
+fn compare_text(text: &str, op: SegmentConditionOp, value: &SegmentValue) -> Result<bool, String> {
+    let expected = value.as_text().unwrap_or_default().to_lowercase();
+    let text = text.trim().to_lowercase();
+    Ok(match op {
+        SegmentConditionOp::Is => text == expected,
+        SegmentConditionOp::Not => text != expected,
+        SegmentConditionOp::Contains => text.contains(&expected),
+        SegmentConditionOp::Notcontain => !text.contains(&expected),
+        SegmentConditionOp::Starts => text.starts_with(&expected),
+        SegmentConditionOp::Ends => text.ends_with(&expected),
+        SegmentConditionOp::Blank => text.is_empty(),
+        SegmentConditionOp::BlankNot => !text.is_empty(),
+        SegmentConditionOp::Greater | SegmentConditionOp::Less => {
+            match (text.parse::<f64>(), expected.parse::<f64>()) {
+                (Ok(a), Ok(b)) => return compare_number(a, op, b),
+                _ if op == SegmentConditionOp::Greater => text > expected,
+                _ => text < expected,
+            }
+        }
+        _ => return Err(unsupported_op(op)),
+    })
+}
+
+fn compare_number(number: f64, op: SegmentConditionOp, value: f64) -> Result<bool, String> {
+    Ok(match op {
+        SegmentConditionOp::Is => (number - value).abs() < f64::EPSILON,
+        SegmentConditionOp::Not => (number - value).abs() >= f64::EPSILON,
+        SegmentConditionOp::Greater => number > value,
+        SegmentConditionOp::Less => number < value,
+        _ => return Err(unsupported_op(op)),
+    })
+}
+
+///
+/// Days since 1970-01-01 of a date that starts with YYYY-MM-DD
+///
+fn parse_day(value: &str) -> Option<i64> {
+    let value = value.trim();
+    let year: i64 = value.get(0..4)?.parse().ok()?;
+    let month: i64 = value.get(5..7)?.parse().ok()?;
+    let day: i64 = value.get(8..10)?.parse().ok()?;
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
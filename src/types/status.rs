@@ -0,0 +1,187 @@
+//! Implement the status enums shared by the resources
+//!
+//! All of them are serialized as the plain string that Mailchimp uses and keep
+//! any value the library doesn't know yet in `Unknown`, so new values on the
+//! server side don't break the deserialization.
+//!
+
+use serde::{Deserialize, Serialize};
+
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $( $(#[$vmeta:meta])* $variant:ident => $value:literal, )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $( $(#[$vmeta])* $variant, )*
+            /// Value not known by the library
+            Unknown(String),
+        }
+
+        impl $name {
+            ///
+            /// Value used by the Mailchimp API
+            ///
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( $name::$variant => $value, )*
+                    $name::Unknown(v) => v.as_str(),
+                }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name::Unknown(String::new())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $( $value => $name::$variant, )*
+                    _ => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl<'a> From<&'a str> for $name {
+            fn from(value: &'a str) -> Self {
+                $name::from(value.to_string())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.as_str().to_string()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
+
+string_enum! {
+    ///
+    /// Subscriber’s status in a list
+    ///
+    pub enum MemberStatus {
+        /// The member is subscribed
+        Subscribed => "subscribed",
+        /// The member unsubscribed
+        Unsubscribed => "unsubscribed",
+        /// The address bounced and was removed
+        Cleaned => "cleaned",
+        /// Waiting for the double opt-in confirmation
+        Pending => "pending",
+        /// Only receives transactional emails
+        Transactional => "transactional",
+        /// The member was archived
+        Archived => "archived",
+    }
+}
+
+string_enum! {
+    ///
+    /// The current status of a campaign
+    ///
+    pub enum CampaignStatus {
+        /// Draft, not sent yet
+        Save => "save",
+        /// RSS campaign paused
+        Paused => "paused",
+        /// Scheduled
+        Schedule => "schedule",
+        /// Sending
+        Sending => "sending",
+        /// Already sent
+        Sent => "sent",
+        /// Send canceled
+        Canceled => "canceled",
+        /// The send is being canceled
+        Canceling => "canceling",
+        /// Archived
+        Archived => "archived",
+    }
+}
+
+string_enum! {
+    ///
+    /// Type of campaign
+    ///
+    pub enum CampaignKind {
+        /// Regular campaign
+        Regular => "regular",
+        /// Plain-text campaign
+        Plaintext => "plaintext",
+        /// A/B Split campaign (deprecated, use variate)
+        Absplit => "absplit",
+        /// RSS-driven campaign
+        Rss => "rss",
+        /// Multivariate campaign
+        Variate => "variate",
+        /// Automation email
+        Automation => "automation",
+    }
+}
+
+string_enum! {
+    ///
+    /// The current status of an Automation workflow or one of its emails
+    ///
+    pub enum AutomationStatus {
+        /// Draft
+        Save => "save",
+        /// Paused
+        Paused => "paused",
+        /// Sending
+        Sending => "sending",
+        /// Archived
+        Archived => "archived",
+    }
+}
+
+string_enum! {
+    ///
+    /// How the winner of a Multivariate Campaign is chosen
+    ///
+    pub enum WinnerCriteria {
+        /// Highest open rate
+        Opens => "opens",
+        /// Highest click rate
+        Clicks => "clicks",
+        /// Highest total revenue
+        TotalRevenue => "total_revenue",
+        /// Chosen in the Mailchimp web application
+        Manual => "manual",
+    }
+}
+
+impl MemberStatus {
+    ///
+    /// Return true when the member can receive campaigns
+    ///
+    pub fn is_subscribed(&self) -> bool {
+        *self == MemberStatus::Subscribed
+    }
+}
+
+impl CampaignStatus {
+    ///
+    /// Return true when the campaign has not been sent or scheduled yet
+    ///
+    pub fn is_draft(&self) -> bool {
+        *self == CampaignStatus::Save
+    }
+}
@@ -0,0 +1,99 @@
+use crate::internal::error_type::MailchimpErrorType;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+
+///
+/// Subscriber Hash
+///
+/// The MD5 hash of the lowercase version of the list member’s email address,
+/// used to address a member in the endpoints `/lists/{list_id}/members/{subscriber_hash}`.
+///
+/// ```
+///     use mailchimp::types::SubscriberHash;
+///     use std::convert::TryFrom;
+///
+///     let hash = SubscriberHash::from_email(" Urist.McVankab@FreddiesJokes.com ");
+///     assert_eq!(hash.as_str(), "62eeb292278cc15f5817cb78f7790b08");
+///     // Strings with an @ are emails, anything else must be a valid hash
+///     assert_eq!(SubscriberHash::try_from("urist.mcvankab@freddiesjokes.com").unwrap(), hash);
+///     assert_eq!(SubscriberHash::try_from("62EEB292278CC15F5817CB78F7790B08").unwrap(), hash);
+///     assert!(SubscriberHash::try_from("../62eeb292").is_err());
+/// ```
+///
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[serde(transparent)]
+pub struct SubscriberHash(String);
+
+impl SubscriberHash {
+    ///
+    /// Hash of an email address. The email is trimmed and lowercased first.
+    ///
+    pub fn from_email(email: &str) -> Self {
+        let normalized = email.trim().to_lowercase();
+        SubscriberHash(format!("{:x}", md5::compute(normalized.as_bytes())))
+    }
+
+    ///
+    /// Use a hash already computed, `None` if it isn't a valid MD5 hex digest
+    ///
+    pub fn from_hash(hash: &str) -> Option<Self> {
+        let hash = hash.trim();
+        if hash.len() == 32 && hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            Some(SubscriberHash(hash.to_lowercase()))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// The hash as a string
+    ///
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'a> TryFrom<&'a str> for SubscriberHash {
+    type Error = MailchimpErrorType;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        if value.contains('@') {
+            return Ok(SubscriberHash::from_email(value));
+        }
+        SubscriberHash::from_hash(value).ok_or_else(|| {
+            MailchimpErrorType::invalid_request(&format!(
+                "`{}` is not an email address or a subscriber hash",
+                value
+            ))
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a String> for SubscriberHash {
+    type Error = MailchimpErrorType;
+
+    fn try_from(value: &'a String) -> Result<Self, Self::Error> {
+        SubscriberHash::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<String> for SubscriberHash {
+    type Error = MailchimpErrorType;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        SubscriberHash::try_from(value.as_str())
+    }
+}
+
+impl<'a> From<&'a SubscriberHash> for SubscriberHash {
+    fn from(value: &'a SubscriberHash) -> Self {
+        value.clone()
+    }
+}
+
+impl fmt::Display for SubscriberHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
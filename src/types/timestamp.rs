@@ -0,0 +1,77 @@
+//! Helpers for the dates returned by the resources
+//!
+//! Mailchimp returns the dates in ISO 8601 format and they are kept as
+//! `String` in the resources. With the `chrono` feature enabled the resources
+//! also have `*_utc` accessors, e.g. `CampaignType::send_time_utc`, that parse
+//! them into `DateTime<Utc>`. Mailchimp sometimes returns an empty string
+//! instead of a date, in that case the accessors return `None`.
+//!
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+///
+/// Parse the dates returned by Mailchimp. Besides RFC 3339 it accepts
+/// `2015-10-21 15:41:36`, `2015-10-21` and `2015-10` (growth history month).
+/// Empty strings return `None`.
+///
+#[cfg(feature = "chrono")]
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Some(Utc.from_utc_datetime(&dt));
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(&format!("{}-01", value), "%Y-%m-%d"))
+        .ok()?;
+    Some(Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?))
+}
+
+///
+/// Days since 1970-01-01 of a civil date, see
+/// http://howardhinnant.github.io/date_algorithms.html
+///
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+///
+/// Number of days of a month (1-12) of the proleptic Gregorian calendar
+///
+pub(crate) fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+///
+/// Civil date (year, month, day) of the days since 1970-01-01, the inverse of
+/// `days_from_civil`
+///
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
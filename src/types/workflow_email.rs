@@ -1,6 +1,7 @@
 use super::automation_campaign::{
     AutomationCampaignSettingsType, AutomationDelayType, AutomationTrackingOptionsType,
     AutomationTriggerType, CampaignReportSummaryType, RecipientType, SocialCardType,
+    UpdateParamsForWorkflowEmail,
 };
 use super::automation_email_queue::{
     AutomationEmailQueueBuilder, AutomationEmailQueueFilter, AutomationEmailQueueType,
@@ -8,10 +9,15 @@ use super::automation_email_queue::{
 };
 use super::empty::EmptyType;
 use super::link::LinkType;
+use super::status::AutomationStatus;
+#[cfg(feature = "chrono")]
+use super::timestamp::parse_timestamp;
 use crate::api::{MailchimpApi, MailchimpApiUpdate};
 use crate::internal::error_type::MailchimpErrorType;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{MalchimpIter, ResourceFilter};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -44,6 +50,9 @@ pub struct WorkflowEmailType {
     /// The date and time the campaign was started in ISO 8601 format.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start_time: Option<String>,
+    /// The current status of the Automation email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<AutomationStatus>,
     /// The link to the campaign’s archive version in ISO 8601 format.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archive_url: Option<String>,
@@ -102,6 +111,30 @@ impl MailchimpApiUpdate for WorkflowEmailType {
 }
 
 impl WorkflowEmailType {
+    ///
+    /// `create_time` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn create_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.create_time.as_deref().and_then(parse_timestamp)
+    }
+
+    ///
+    /// `start_time` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn start_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.start_time.as_deref().and_then(parse_timestamp)
+    }
+
+    ///
+    /// `send_time` as `DateTime<Utc>`, `None` when it's empty or not a valid date
+    ///
+    #[cfg(feature = "chrono")]
+    pub fn send_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.send_time.as_deref().and_then(parse_timestamp)
+    }
+
     // ============== Actions ==============
     ///
     /// Detiene un email automatizado
@@ -139,6 +172,34 @@ impl WorkflowEmailType {
         }
     }
 
+    ///
+    /// Update the settings or the delay of the Automation workflow email and
+    /// return the updated email. Only the given values are changed.
+    ///
+    /// Argumentos:
+    ///     settings: Settings for the campaign including the email subject, from name, and from email address.
+    ///     delay: The delay settings for an automation email.
+    ///
+    pub fn update(
+        &self,
+        settings: Option<AutomationCampaignSettingsType>,
+        delay: Option<AutomationDelayType>,
+    ) -> MailchimpResult<WorkflowEmailType> {
+        // PATCH /automations/{workflow_id}/emails/{workflow_email_id}
+        if settings.is_none() && delay.is_none() {
+            return Err(MailchimpErrorType::invalid_request(
+                "Nothing to update, set the settings or the delay",
+            ));
+        }
+        let payload = UpdateParamsForWorkflowEmail { settings, delay };
+        let mut email = self
+            ._api
+            .patch::<WorkflowEmailType, UpdateParamsForWorkflowEmail>(&self._endpoint, payload)?;
+        email.set_api(self._api.clone());
+        email.set_endpoint(&self._endpoint);
+        Ok(email)
+    }
+
     ///
     /// Removes an individual Automation workflow email. Emails from certain workflow types,
     /// including the Abandoned Cart Email (abandonedCart) and Product Retargeting Email